@@ -0,0 +1,18 @@
+pub struct Autostart {
+    command: &'static [&'static str],
+    once: bool,
+}
+
+impl Autostart {
+    pub const fn new(command: &'static [&'static str], once: bool) -> Self {
+        Self { command, once }
+    }
+
+    pub fn command(&self) -> &'static [&'static str] {
+        self.command
+    }
+
+    pub fn once(&self) -> bool {
+        self.once
+    }
+}
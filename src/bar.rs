@@ -1,9 +1,9 @@
-use std::ptr::NonNull;
+use std::{cell::Cell, ptr::NonNull};
 
 use pangocairo::pango;
 use xcb::{x, Xid};
 
-use crate::{config, ffi};
+use crate::{buttons::Click, config, ffi};
 
 struct Tag {
     width: u16,
@@ -39,6 +39,7 @@ pub struct Bar {
     main_text_middle: f64,
     tag_text_middle: f64,
     tags: Tags,
+    status_position: Cell<u16>,
 }
 
 impl Bar {
@@ -57,7 +58,7 @@ impl Bar {
             border_width: 0,
             class: x::WindowClass::InputOutput,
             visual: screen.root_visual(),
-            value_list: &[],
+            value_list: &[x::Cw::EventMask(x::EventMask::BUTTON_PRESS)],
         });
         connection.send_request(&x::MapWindow { window });
 
@@ -120,11 +121,35 @@ impl Bar {
             main_text_middle,
             tag_text_middle,
             tags,
+            status_position: Cell::new(width),
+        }
+    }
+
+    pub fn window(&self) -> x::Window {
+        self.window
+    }
+
+    pub fn click(&self, x: i16) -> (Click, Option<usize>) {
+        let x = x.max(0) as u16;
+        let mut position = 0;
+
+        for (i, tag) in self.tags.tags.iter().enumerate() {
+            position += tag.width;
+
+            if x < position {
+                return (Click::TagBar, Some(i));
+            }
+        }
+
+        if x < self.status_position.get() {
+            (Click::WindowTitle, None)
+        } else {
+            (Click::StatusText, None)
         }
     }
 
     pub fn init(&self) {
-        self.draw_tags(0, Vec::new());
+        self.draw_tags(0, Vec::new(), Vec::new());
     }
 
     pub fn update(&mut self, connection: &xcb::Connection, x: i16, y: i16, width: u16) {
@@ -153,7 +178,12 @@ impl Bar {
         });
     }
 
-    pub fn draw_tags(&self, selected: usize, full_workspaces: Vec<usize>) {
+    pub fn draw_tags(
+        &self,
+        selected: usize,
+        full_workspaces: Vec<usize>,
+        urgent_workspaces: Vec<usize>,
+    ) {
         let mut position = 0;
 
         for (i, tag) in self.tags.tags.iter().enumerate() {
@@ -163,6 +193,9 @@ impl Bar {
             if i == selected {
                 box_color = crate::config::BAR_HL_COLOR;
                 text_color = crate::config::BAR_TEXT_HL_COLOR;
+            } else if urgent_workspaces.contains(&i) {
+                box_color = crate::config::BAR_URGENT_COLOR;
+                text_color = crate::config::BAR_TEXT_HL_COLOR;
             } else {
                 box_color = crate::config::BAR_COLOR;
                 text_color = crate::config::BAR_TEXT_COLOR;
@@ -179,16 +212,54 @@ impl Bar {
         }
     }
 
-    pub fn draw_status(&self, name: &str, status: &str) {
+    pub fn draw_status(&self, mode: Option<&str>, name: &str, minimized: &[String], status: &str) {
         self.main_layout.set_text(status);
 
         let status_width =
             (self.main_layout.size().0 / pango::SCALE) as u16 + 2 * crate::config::TEXT_MARGIN;
         let status_position = self.width - status_width;
-        let name_width = self.width - self.tags.width - status_width;
+        self.status_position.set(status_position);
+
+        let minimized = minimized.join(" | ");
+        let minimized_width = if minimized.is_empty() {
+            0
+        } else {
+            self.main_layout.set_text(&minimized);
+            (self.main_layout.size().0 / pango::SCALE) as u16 + 2 * crate::config::TEXT_MARGIN
+        };
+        let minimized_position = status_position.saturating_sub(minimized_width);
+
+        let mode_width = match mode {
+            Some(mode) => {
+                self.main_layout.set_text(mode);
+                (self.main_layout.size().0 / pango::SCALE) as u16 + 2 * crate::config::TEXT_MARGIN
+            }
+            None => 0,
+        };
+        let name_position = self.tags.width + mode_width;
 
-        self.draw_rectangle(self.tags.width, name_width, crate::config::BAR_HL_COLOR);
-        self.draw_main_text(self.tags.width, name, crate::config::BAR_TEXT_HL_COLOR);
+        let name_width = minimized_position.saturating_sub(name_position);
+
+        if let Some(mode) = mode {
+            self.draw_rectangle(self.tags.width, mode_width, crate::config::BAR_MODE_COLOR);
+            self.draw_main_text(self.tags.width, mode, crate::config::BAR_TEXT_HL_COLOR);
+        }
+
+        self.draw_rectangle(name_position, name_width, crate::config::BAR_HL_COLOR);
+        self.draw_main_text(name_position, name, crate::config::BAR_TEXT_HL_COLOR);
+
+        if minimized_width > 0 {
+            self.draw_rectangle(
+                minimized_position,
+                minimized_width,
+                crate::config::BAR_COLOR,
+            );
+            self.draw_main_text(
+                minimized_position,
+                &minimized,
+                crate::config::BAR_TEXT_COLOR,
+            );
+        }
 
         self.draw_rectangle(status_position, status_width, crate::config::BAR_COLOR);
         self.draw_main_text(status_position, status, crate::config::BAR_TEXT_COLOR);
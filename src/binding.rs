@@ -0,0 +1,82 @@
+use std::fmt;
+
+use xcb::x;
+
+use crate::{
+    buttons::{ButtonCombo, Click},
+    keys::{self, KeyCombo},
+};
+
+pub enum Binding {
+    Key(KeyCombo),
+    Button(ButtonCombo),
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    Empty,
+    UnknownModifier(String),
+    UnknownKey(String),
+    UnknownButton(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty binding"),
+            ParseError::UnknownModifier(modifier) => write!(f, "unknown modifier {}", modifier),
+            ParseError::UnknownKey(key) => write!(f, "unknown key {}", key),
+            ParseError::UnknownButton(button) => write!(f, "unknown button {}", button),
+        }
+    }
+}
+
+// Parses bindings like "Mod4+Shift+Return" or "Super+button3", button bindings apply to
+// client windows.
+pub fn parse(binding: &str) -> Result<Binding, ParseError> {
+    let mut parts: Vec<&str> = binding.split('+').map(str::trim).collect();
+
+    let key = match parts.pop() {
+        Some(key) if !key.is_empty() => key,
+        _ => return Err(ParseError::Empty),
+    };
+
+    let mask = parts
+        .into_iter()
+        .try_fold(x::KeyButMask::empty(), |mask, modifier| {
+            Ok(mask | parse_modifier(modifier)?)
+        })?;
+
+    if let Some(button) = key
+        .get(..6)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("button"))
+        .map(|_| &key[6..])
+    {
+        return match button.parse() {
+            Ok(button @ 1..=5) => Ok(Binding::Button(ButtonCombo::new(
+                Click::ClientWindow,
+                mask,
+                button,
+            ))),
+            _ => Err(ParseError::UnknownButton(key.to_string())),
+        };
+    }
+
+    keys::keysym_from_name(key)
+        .map(|keysym| Binding::Key(KeyCombo::new(mask, keysym)))
+        .ok_or_else(|| ParseError::UnknownKey(key.to_string()))
+}
+
+fn parse_modifier(modifier: &str) -> Result<x::KeyButMask, ParseError> {
+    match modifier.to_ascii_lowercase().as_str() {
+        "shift" => Ok(x::KeyButMask::SHIFT),
+        "lock" => Ok(x::KeyButMask::LOCK),
+        "control" | "ctrl" => Ok(x::KeyButMask::CONTROL),
+        "mod1" | "alt" => Ok(x::KeyButMask::MOD1),
+        "mod2" => Ok(x::KeyButMask::MOD2),
+        "mod3" => Ok(x::KeyButMask::MOD3),
+        "mod4" | "super" => Ok(x::KeyButMask::MOD4),
+        "mod5" => Ok(x::KeyButMask::MOD5),
+        _ => Err(ParseError::UnknownModifier(modifier.to_string())),
+    }
+}
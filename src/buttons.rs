@@ -1,18 +1,43 @@
 use xcb::x;
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Click {
+    TagBar,
+    WindowTitle,
+    StatusText,
+    ClientWindow,
+    RootWindow,
+}
+
 #[derive(Eq, PartialEq, Hash)]
 pub struct ButtonCombo {
+    click: Click,
     mask: x::KeyButMask,
     button: u8,
 }
 
 impl ButtonCombo {
-    pub const fn new(mask: x::KeyButMask, button: u8) -> Self {
-        Self { mask, button }
+    pub const fn new(click: Click, mask: x::KeyButMask, button: u8) -> Self {
+        Self {
+            click,
+            mask,
+            button,
+        }
+    }
+
+    pub fn click(&self) -> Click {
+        self.click
     }
 
-    pub fn button(&self) -> u8 {
-        self.button
+    pub fn button(&self) -> x::ButtonIndex {
+        match self.button {
+            1 => x::ButtonIndex::N1,
+            2 => x::ButtonIndex::N2,
+            3 => x::ButtonIndex::N3,
+            4 => x::ButtonIndex::N4,
+            5 => x::ButtonIndex::N5,
+            _ => x::ButtonIndex::Any,
+        }
     }
 
     pub fn mask(&self) -> x::KeyButMask {
@@ -1,5 +1,9 @@
+use std::time::Instant;
+
 use xcb::x;
 
+use crate::hints::SizeHints;
+
 #[derive(Debug)]
 pub struct Client {
     pub window: x::Window,
@@ -14,6 +18,17 @@ pub struct Client {
     pub old_width: u16,
     pub old_height: u16,
     pub old_floating: bool,
+    pub urgent: Option<Instant>,
+    pub input: bool,
+    pub size_hints: SizeHints,
+    pub transient_for: Option<x::Window>,
+    pub hidden: bool,
+    pub minimized: Option<Instant>,
+    pub ignore_unmap: u32,
+    pub scratchpad: Option<&'static str>,
+    pub pid: Option<u32>,
+    pub terminal: bool,
+    pub swallowed: Option<Box<Client>>,
 }
 
 impl Client {
@@ -39,6 +54,27 @@ impl Client {
             old_width: width,
             old_height: height,
             old_floating: false,
+            urgent: None,
+            input: true,
+            size_hints: SizeHints::default(),
+            transient_for: None,
+            hidden: false,
+            minimized: None,
+            ignore_unmap: 0,
+            scratchpad: None,
+            pid: None,
+            terminal: false,
+            swallowed: None,
+        }
+    }
+
+    pub fn ignore_unmap(&mut self) -> bool {
+        if self.ignore_unmap > 0 {
+            self.ignore_unmap -= 1;
+
+            true
+        } else {
+            false
         }
     }
 }
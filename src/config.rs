@@ -1,9 +1,13 @@
 use xcb::x::KeyButMask;
 
 use crate::{
-    buttons::ButtonCombo,
+    autostart::Autostart,
+    buttons::{ButtonCombo, Click},
     keys::{self, KeyCombo},
-    rwm::Rwm,
+    modes::Mode,
+    monitor::Direction,
+    rwm::{FocusMode, Rwm},
+    scratchpad::{Matcher, Scratchpad},
 };
 
 tags!("", "", "", "", "", "", "", "", "");
@@ -15,6 +19,28 @@ pub const TAG_FONT: &str =
 pub const MARGIN: u16 = 10;
 pub const TEXT_MARGIN: u16 = 12;
 
+pub const FOCUS_MODE: FocusMode = FocusMode::Sloppy;
+
+pub const RESIZE_HINTS: bool = false;
+pub const RAISE_ON_FOCUS: bool = true;
+pub const RAISE_ON_CLICK: bool = true;
+pub const HIDE_BY_UNMAP: bool = false;
+
+pub const TERMINALS: &[&str] = &["St"];
+pub const NO_SWALLOW: &[&str] = &["Xephyr", "xev"];
+
+pub const MIN_SIZE: u16 = 32;
+pub const SNAP: u16 = 16;
+pub const EDGE_TILING: bool = true;
+
+pub const MODE_TIMEOUT: u64 = 2000;
+pub const SCRATCHPAD_TIMEOUT: u64 = 5000;
+
+pub const CONFIRM_QUIT: bool = true;
+pub const QUIT_MODE: &str = "quit";
+pub const QUIT_TIMEOUT: u64 = 5000;
+pub const TERM_TIMEOUT: u64 = 1000;
+
 pub const BORDER_WIDTH: u16 = 2;
 pub const BORDER_COLOR: u32 = 0xabb2bf;
 pub const BORDER_HL_COLOR: u32 = 0x61afef;
@@ -24,41 +50,137 @@ pub const BAR_COLOR: u32 = 0x282d34;
 pub const BAR_HL_COLOR: u32 = 0x61afef;
 pub const BAR_TEXT_COLOR: u32 = 0xcccccc;
 pub const BAR_TEXT_HL_COLOR: u32 = 0xeeeeee;
+pub const BAR_URGENT_COLOR: u32 = 0xe06c75;
+pub const BAR_MODE_COLOR: u32 = 0xc678dd;
 
+const NOMOD: KeyButMask = KeyButMask::empty();
 const MOD: KeyButMask = KeyButMask::MOD4;
 const MODSHIFT: KeyButMask = MOD.union(KeyButMask::SHIFT);
 
-keys!(
-    (MODSHIFT, keys::XK_Return, spawn!("st")),
-    (MOD, keys::XK_p, spawn!("rmenu_run")),
-    (MOD, keys::XK_e, spawn!("microsoft-edge-stable")),
+bindings!(
+    ("Mod4+p", spawn!("rmenu_run")),
+    ("Mod4+e", spawn!("microsoft-edge-stable")),
+    ("Mod4+Shift+c", kill!()),
+    ("Mod4+Return", swap!()),
+    ("Mod4+Shift+Return", spawn!("st")),
+    ("Mod4+f", toggle_fullscreen!()),
+    ("Mod4+space", toggle_floating!()),
+    ("Mod4+button2", toggle_floating!()),
+    ("Mod4+n", minimize!()),
+    ("Mod4+Shift+n", restore_last!()),
+    ("Mod4+Left", main_factor!(-0.05)),
+    ("Mod4+Right", main_factor!(0.05)),
+    ("Mod4+Control+Left", move_floating!(-20, 0)),
+    ("Mod4+Control+Right", move_floating!(20, 0)),
+    ("Mod4+Control+Up", move_floating!(0, -20)),
+    ("Mod4+Control+Down", move_floating!(0, 20)),
+    ("Mod4+Control+Shift+Left", resize_floating!(-20, 0)),
+    ("Mod4+Control+Shift+Right", resize_floating!(20, 0)),
+    ("Mod4+Control+Shift+Up", resize_floating!(0, -20)),
+    ("Mod4+Control+Shift+Down", resize_floating!(0, 20)),
+    ("Mod4+x", center_floating!()),
+    ("Mod4+Control+h", snap_floating!(Direction::Left)),
+    ("Mod4+Control+l", snap_floating!(Direction::Right)),
+    ("Mod4+Control+k", snap_floating!(Direction::Top)),
+    ("Mod4+Control+j", snap_floating!(Direction::Bottom)),
+    ("Mod4+1", view!(0)),
+    ("Mod4+2", view!(1)),
+    ("Mod4+3", view!(2)),
+    ("Mod4+4", view!(3)),
+    ("Mod4+5", view!(4)),
+    ("Mod4+6", view!(5)),
+    ("Mod4+7", view!(6)),
+    ("Mod4+8", view!(7)),
+    ("Mod4+9", view!(8)),
+    ("Mod4+Shift+1", tag!(0)),
+    ("Mod4+Shift+2", tag!(1)),
+    ("Mod4+Shift+3", tag!(2)),
+    ("Mod4+Shift+4", tag!(3)),
+    ("Mod4+Shift+5", tag!(4)),
+    ("Mod4+Shift+6", tag!(5)),
+    ("Mod4+Shift+7", tag!(6)),
+    ("Mod4+Shift+8", tag!(7)),
+    ("Mod4+Shift+9", tag!(8)),
+    ("Mod4+Shift+period", tagmon!()),
+    ("Mod4+grave", toggle_scratchpad!("term")),
+    ("Mod4+c", toggle_scratchpad!("calc")),
+    ("Mod4+u", focus_urgent!()),
+    ("Mod4+Control+s", focus_mode!(FocusMode::Sloppy)),
+    ("Mod4+Control+c", focus_mode!(FocusMode::Click)),
+    ("Mod4+Control+d", focus_mode!(FocusMode::Delayed(200))),
+    ("Mod4+a", mode!("launch")),
+    ("Mod4+r", mode!("resize")),
+    ("Mod4+Shift+q", quit!()),
+);
+
+release_keys!(
     (MOD, keys::XK_s, spawn!("shot")),
-    (MODSHIFT, keys::XK_c, kill!()),
-    (MOD, keys::XK_Return, swap!()),
-    (MOD, keys::XK_f, toggle_fullscreen!()),
-    (MOD, keys::XK_space, toggle_floating!()),
-    (MOD, keys::XK_Left, main_factor!(-0.05)),
-    (MOD, keys::XK_Right, main_factor!(0.05)),
-    (MOD, keys::XK_1, view!(0)),
-    (MOD, keys::XK_2, view!(1)),
-    (MOD, keys::XK_3, view!(2)),
-    (MOD, keys::XK_4, view!(3)),
-    (MOD, keys::XK_5, view!(4)),
-    (MOD, keys::XK_6, view!(5)),
-    (MOD, keys::XK_7, view!(6)),
-    (MOD, keys::XK_8, view!(7)),
-    (MOD, keys::XK_9, view!(8)),
-    (MODSHIFT, keys::XK_1, tag!(0)),
-    (MODSHIFT, keys::XK_2, tag!(1)),
-    (MODSHIFT, keys::XK_3, tag!(2)),
-    (MODSHIFT, keys::XK_4, tag!(3)),
-    (MODSHIFT, keys::XK_5, tag!(4)),
-    (MODSHIFT, keys::XK_6, tag!(5)),
-    (MODSHIFT, keys::XK_7, tag!(6)),
-    (MODSHIFT, keys::XK_8, tag!(7)),
-    (MODSHIFT, keys::XK_9, tag!(8)),
-    (MODSHIFT, keys::XK_period, tagmon!()),
-    (MODSHIFT, keys::XK_q, quit!()),
+    (
+        MODSHIFT,
+        keys::XK_s,
+        shell!("shot && notify-send 'Screenshot taken'")
+    ),
+    (NOMOD, keys::XK_Super_L, spawn!("rmenu_run")),
 );
 
-buttons!((MOD, 1, drag!()), (MOD, 3, resize!()));
+modes!(
+    (
+        "launch",
+        false,
+        mode_keys!(
+            (NOMOD, keys::XK_t, spawn!("st")),
+            (NOMOD, keys::XK_e, spawn!("microsoft-edge-stable")),
+            (NOMOD, keys::XK_h, spawn!("st", "-e", "htop")),
+            (NOMOD, keys::XK_s, spawn!("shot")),
+        )
+    ),
+    (
+        "resize",
+        true,
+        mode_keys!(
+            (NOMOD, keys::XK_Left, resize_floating!(-20, 0)),
+            (NOMOD, keys::XK_Right, resize_floating!(20, 0)),
+            (NOMOD, keys::XK_Up, resize_floating!(0, -20)),
+            (NOMOD, keys::XK_Down, resize_floating!(0, 20)),
+            (NOMOD, keys::XK_Escape, exit_mode!()),
+        )
+    ),
+    (
+        QUIT_MODE,
+        false,
+        mode_keys!((NOMOD, keys::XK_y, quit_confirmed!()))
+    ),
+);
+
+autostart!(
+    (&["picom"], true),
+    (&["dunst"], true),
+    (&["rstatus"], false),
+);
+
+scratchpads!(
+    (
+        "term",
+        &["st", "-n", "scratchpad"],
+        Matcher::Class("scratchpad")
+    ),
+    (
+        "calc",
+        &["st", "-t", "calculator", "-e", "bc", "-lq"],
+        Matcher::Title("calculator")
+    ),
+);
+
+buttons!(
+    (Click::TagBar, NOMOD, 1, view_clicked!()),
+    (Click::TagBar, MOD, 1, tag_clicked!()),
+    (Click::TagBar, NOMOD, 4, cycle_view!(-1)),
+    (Click::TagBar, NOMOD, 5, cycle_view!(1)),
+    (Click::WindowTitle, NOMOD, 2, restore_last!()),
+    (Click::WindowTitle, NOMOD, 4, cycle_view!(-1)),
+    (Click::WindowTitle, NOMOD, 5, cycle_view!(1)),
+    (Click::StatusText, NOMOD, 4, cycle_view!(-1)),
+    (Click::StatusText, NOMOD, 5, cycle_view!(1)),
+    (Click::ClientWindow, MOD, 1, drag!()),
+    (Click::ClientWindow, MOD, 3, resize!()),
+);
@@ -0,0 +1,183 @@
+const INPUT_HINT: u32 = 1;
+const URGENCY_HINT: u32 = 1 << 8;
+
+pub struct WmHints {
+    data: Vec<u32>,
+}
+
+impl WmHints {
+    pub fn new(data: &[u32]) -> Self {
+        Self {
+            data: data.to_vec(),
+        }
+    }
+
+    pub fn data(&self) -> &[u32] {
+        &self.data
+    }
+
+    pub fn input(&self) -> bool {
+        self.flags() & INPUT_HINT == 0 || self.data.get(1).is_none_or(|input| *input != 0)
+    }
+
+    pub fn urgent(&self) -> bool {
+        self.flags() & URGENCY_HINT != 0
+    }
+
+    pub fn clear_urgent(&mut self) {
+        if let Some(flags) = self.data.first_mut() {
+            *flags &= !URGENCY_HINT;
+        }
+    }
+
+    fn flags(&self) -> u32 {
+        self.data.first().copied().unwrap_or(0)
+    }
+}
+
+const P_MIN_SIZE: u32 = 1 << 4;
+const P_MAX_SIZE: u32 = 1 << 5;
+const P_RESIZE_INC: u32 = 1 << 6;
+const P_ASPECT: u32 = 1 << 7;
+const P_BASE_SIZE: u32 = 1 << 8;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SizeHints {
+    min_width: u16,
+    min_height: u16,
+    max_width: u16,
+    max_height: u16,
+    width_inc: u16,
+    height_inc: u16,
+    min_aspect: f64,
+    max_aspect: f64,
+    base_width: u16,
+    base_height: u16,
+}
+
+impl SizeHints {
+    pub fn new(data: &[u32]) -> Self {
+        let field = |i: usize| data.get(i).copied().unwrap_or(0);
+        // Sizes are signed 32 bit values on the wire
+        let size = |i: usize| (field(i) as i32).clamp(0, u16::MAX as i32) as u16;
+        let flags = field(0);
+
+        let mut hints = Self::default();
+
+        if flags & P_MIN_SIZE != 0 {
+            hints.min_width = size(5);
+            hints.min_height = size(6);
+        }
+
+        if flags & P_MAX_SIZE != 0 {
+            hints.max_width = size(7);
+            hints.max_height = size(8);
+        }
+
+        if flags & P_RESIZE_INC != 0 {
+            hints.width_inc = size(9);
+            hints.height_inc = size(10);
+        }
+
+        if flags & P_ASPECT != 0 && (11..=14).all(|i| field(i) as i32 > 0) {
+            hints.min_aspect = field(11) as f64 / field(12) as f64;
+            hints.max_aspect = field(13) as f64 / field(14) as f64;
+        }
+
+        if flags & P_BASE_SIZE != 0 {
+            hints.base_width = size(15);
+            hints.base_height = size(16);
+        }
+
+        // ICCCM 4.1.2.3: the base size and the minimum size stand in for each other
+        if flags & P_BASE_SIZE == 0 {
+            hints.base_width = hints.min_width;
+            hints.base_height = hints.min_height;
+        }
+
+        if flags & P_MIN_SIZE == 0 {
+            hints.min_width = hints.base_width;
+            hints.min_height = hints.base_height;
+        }
+
+        if hints.max_width > 0 {
+            hints.max_width = hints.max_width.max(hints.min_width);
+        }
+
+        if hints.max_height > 0 {
+            hints.max_height = hints.max_height.max(hints.min_height);
+        }
+
+        hints
+    }
+
+    pub fn fixed(&self) -> bool {
+        self.max_width > 0
+            && self.max_height > 0
+            && self.min_width == self.max_width
+            && self.min_height == self.max_height
+    }
+
+    pub fn min_width(&self) -> u16 {
+        self.min_width
+    }
+
+    pub fn min_height(&self) -> u16 {
+        self.min_height
+    }
+
+    pub fn apply(&self, width: u16, height: u16) -> (u16, u16) {
+        let base_is_min = self.base_width == self.min_width && self.base_height == self.min_height;
+
+        let mut width = width as f64;
+        let mut height = height as f64;
+
+        if !base_is_min {
+            width -= self.base_width as f64;
+            height -= self.base_height as f64;
+        }
+
+        if self.min_aspect > 0. && self.max_aspect > 0. && width > 0. && height > 0. {
+            if width / height > self.max_aspect {
+                width = (height * self.max_aspect).round();
+            } else if width / height < self.min_aspect {
+                height = (width / self.min_aspect).round();
+            }
+        }
+
+        if base_is_min {
+            width -= self.base_width as f64;
+            height -= self.base_height as f64;
+        }
+
+        let mut width = width.max(0.) as u16;
+        let mut height = height.max(0.) as u16;
+
+        if self.width_inc > 0 {
+            width = width.saturating_sub(width % self.width_inc);
+        }
+
+        if self.height_inc > 0 {
+            height = height.saturating_sub(height % self.height_inc);
+        }
+
+        width = width
+            .saturating_add(self.base_width)
+            .max(self.min_width)
+            .max(1);
+        height = height
+            .saturating_add(self.base_height)
+            .max(self.min_height)
+            .max(1);
+
+        if self.max_width > 0 {
+            width = width.min(self.max_width);
+        }
+
+        if self.max_height > 0 {
+            height = height.min(self.max_height);
+        }
+
+        (width, height)
+    }
+}
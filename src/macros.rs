@@ -2,8 +2,32 @@ macro_rules! spawn {
     ( $command:expr ) => {
         |rwm: &mut Rwm| rwm.spawn($command, &[])
     };
-    ( $command:expr, $( $arg:expr )*, $ ) => {
-        |rwm: &mut Rwm| rwm.spawn($command, &[$(arg,)*])
+    ( $command:expr, $( $arg:expr ),+$( , )? ) => {
+        |rwm: &mut Rwm| rwm.spawn($command, &[$($arg),+])
+    };
+}
+
+macro_rules! shell {
+    ( $command:expr ) => {
+        |rwm: &mut Rwm| rwm.shell($command)
+    };
+}
+
+macro_rules! mode {
+    ( $name:expr ) => {
+        |rwm: &mut Rwm| rwm.enter_mode($name)
+    };
+}
+
+macro_rules! exit_mode {
+    () => {
+        |rwm: &mut Rwm| rwm.exit_mode()
+    };
+}
+
+macro_rules! focus_mode {
+    ( $focus_mode:expr ) => {
+        |rwm: &mut Rwm| rwm.set_focus_mode($focus_mode)
     };
 }
 
@@ -19,6 +43,30 @@ macro_rules! swap {
     };
 }
 
+macro_rules! move_floating {
+    ( $x_delta:expr, $y_delta:expr ) => {
+        |rwm: &mut Rwm| rwm.move_floating($x_delta, $y_delta)
+    };
+}
+
+macro_rules! resize_floating {
+    ( $width_delta:expr, $height_delta:expr ) => {
+        |rwm: &mut Rwm| rwm.resize_floating($width_delta, $height_delta)
+    };
+}
+
+macro_rules! center_floating {
+    () => {
+        |rwm: &mut Rwm| rwm.center_floating()
+    };
+}
+
+macro_rules! snap_floating {
+    ( $direction:expr ) => {
+        |rwm: &mut Rwm| rwm.snap_floating($direction)
+    };
+}
+
 macro_rules! main_factor {
     ( $factor:expr ) => {
         |rwm: &mut Rwm| rwm.main_factor($factor)
@@ -55,12 +103,60 @@ macro_rules! tagmon {
     };
 }
 
+macro_rules! focus_urgent {
+    () => {
+        |rwm: &mut Rwm| rwm.focus_urgent()
+    };
+}
+
+macro_rules! minimize {
+    () => {
+        |rwm: &mut Rwm| rwm.minimize()
+    };
+}
+
+macro_rules! restore_last {
+    () => {
+        |rwm: &mut Rwm| rwm.restore_last()
+    };
+}
+
+macro_rules! toggle_scratchpad {
+    ( $name:expr ) => {
+        |rwm: &mut Rwm| rwm.toggle_scratchpad($name)
+    };
+}
+
+macro_rules! quit_confirmed {
+    () => {
+        |rwm: &mut Rwm| rwm.quit_confirmed()
+    };
+}
+
 macro_rules! quit {
     () => {
         |rwm: &mut Rwm| rwm.quit()
     };
 }
 
+macro_rules! view_clicked {
+    () => {
+        |rwm: &mut Rwm| rwm.view_clicked()
+    };
+}
+
+macro_rules! tag_clicked {
+    () => {
+        |rwm: &mut Rwm| rwm.tag_clicked()
+    };
+}
+
+macro_rules! cycle_view {
+    ( $direction:expr ) => {
+        |rwm: &mut Rwm| rwm.cycle_view($direction)
+    };
+}
+
 macro_rules! drag {
     () => {
         |rwm: &mut Rwm| rwm.drag()
@@ -84,15 +180,53 @@ macro_rules! tags {
     };
 }
 
-macro_rules! keys {
+macro_rules! release_keys {
+    ( $( $tup:expr ),*$( , )? ) => {
+        pub const RELEASE_KEYS: [(KeyCombo, fn(&mut Rwm)); count!($($tup)*)] = [$((KeyCombo::on_release($tup.0, $tup.1), $tup.2)),*];
+    };
+}
+
+macro_rules! mode_keys {
     ( $( $tup:expr ),*$( , )? ) => {
-        pub const KEYS: [(KeyCombo, fn(&mut Rwm)); count!($($tup)*)] = [$((KeyCombo::new($tup.0, $tup.1), $tup.2)),*];
+        &[$((KeyCombo::new($tup.0, $tup.1), $tup.2 as fn(&mut Rwm))),*]
+    };
+}
+
+macro_rules! modes {
+    ( $( $tup:expr ),*$( , )? ) => {
+        pub const MODES: [Mode; count!($($tup)*)] = [$(Mode::new($tup.0, $tup.1, $tup.2)),*];
     };
 }
 
 macro_rules! buttons {
     ( $( $tup:expr ),*$( , )? ) => {
-        pub const BUTTONS: [(ButtonCombo, fn(&mut Rwm)); count!($($tup)*)] = [$((ButtonCombo::new($tup.0, $tup.1), $tup.2)),*];
+        pub const BUTTONS: [(ButtonCombo, fn(&mut Rwm)); count!($($tup)*)] = [$((ButtonCombo::new($tup.0, $tup.1, $tup.2), $tup.3)),*];
+    };
+}
+
+macro_rules! autostart {
+    ( $( $tup:expr ),*$( , )? ) => {
+        pub const AUTOSTART: [Autostart; count!($($tup)*)] = [$(Autostart::new($tup.0, $tup.1)),*];
+    };
+}
+
+macro_rules! scratchpads {
+    ( $( $tup:expr ),*$( , )? ) => {
+        pub const SCRATCHPADS: [Scratchpad; count!($($tup)*)] = [$(Scratchpad::new($tup.0, $tup.1, $tup.2)),*];
+    };
+}
+
+macro_rules! keysyms {
+    ( $( pub const $name:ident: x::Keysym = $value:expr; )* ) => {
+        $( pub const $name: x::Keysym = $value; )*
+
+        const KEYSYMS: &[(&str, x::Keysym)] = &[$((stringify!($name), $value)),*];
+    };
+}
+
+macro_rules! bindings {
+    ( $( $tup:expr ),*$( , )? ) => {
+        pub const BINDINGS: [(&str, fn(&mut Rwm)); count!($($tup)*)] = [$(($tup.0, $tup.1)),*];
     };
 }
 
@@ -2,18 +2,24 @@ use rwm::Rwm;
 
 #[macro_use]
 mod macros;
+mod autostart;
 mod bar;
+mod binding;
 mod buttons;
 mod client;
 mod config;
 mod cursor;
 mod ffi;
+mod hints;
 mod keys;
+mod modes;
 mod monitor;
 mod rwm;
+mod scratchpad;
 
 fn main() {
     let mut rwm = Rwm::new();
     rwm.setup();
+    rwm.autostart();
     rwm.run();
 }
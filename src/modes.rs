@@ -0,0 +1,35 @@
+use crate::{keys::KeyCombo, rwm::Rwm};
+
+pub type Binding = (KeyCombo, fn(&mut Rwm));
+
+#[derive(Clone, Copy)]
+pub struct Mode {
+    name: &'static str,
+    persistent: bool,
+    keys: &'static [Binding],
+}
+
+impl Mode {
+    pub const fn new(name: &'static str, persistent: bool, keys: &'static [Binding]) -> Self {
+        Self {
+            name,
+            persistent,
+            keys,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn persistent(&self) -> bool {
+        self.persistent
+    }
+
+    pub fn get(&self, key_combo: &KeyCombo) -> Option<fn(&mut Rwm)> {
+        self.keys
+            .iter()
+            .find(|(combo, _)| combo == key_combo)
+            .map(|(_, command)| *command)
+    }
+}
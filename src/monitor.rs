@@ -1,6 +1,22 @@
+use std::{iter::once, time::Instant};
+
 use xcb::x;
 
-use crate::{bar::Bar, client::Client, config};
+use crate::{bar::Bar, buttons::Click, client::Client, config, hints::SizeHints};
+
+pub enum Direction {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+// The edges a resize moves, None leaves that axis alone
+#[derive(Debug, Clone, Copy)]
+pub struct Edges {
+    pub left: Option<bool>,
+    pub top: Option<bool>,
+}
 
 pub struct Monitor {
     x: i16,
@@ -11,6 +27,7 @@ pub struct Monitor {
     tags: Vec<Vec<Client>>,
     tag: usize,
     main_factor: f64,
+    stack: Vec<x::Window>,
 }
 
 impl Monitor {
@@ -33,6 +50,7 @@ impl Monitor {
             tags,
             tag: 0,
             main_factor: 0.5,
+            stack: Vec::new(),
         }
     }
 
@@ -52,14 +70,156 @@ impl Monitor {
         self.height
     }
 
+    pub fn selected_tag(&self) -> usize {
+        self.tag
+    }
+
     pub fn clients(&self) -> Vec<x::Window> {
         self.tags
             .iter()
             .flatten()
+            .flat_map(|client| once(client).chain(client.swallowed.as_deref()))
             .map(|client| client.window)
             .collect()
     }
 
+    pub fn client(&self, window: x::Window) -> Option<&Client> {
+        self.tags
+            .iter()
+            .flatten()
+            .find(|client| client.window == window)
+    }
+
+    pub fn locate(&self, window: x::Window) -> Option<(usize, &Client)> {
+        self.tags.iter().enumerate().find_map(|(i, clients)| {
+            clients
+                .iter()
+                .find(|client| client.window == window)
+                .map(|client| (i, client))
+        })
+    }
+
+    pub fn minimized(&self) -> Vec<x::Window> {
+        let mut minimized: Vec<&Client> = self.tags[self.tag]
+            .iter()
+            .filter(|client| client.minimized.is_some())
+            .collect();
+        minimized.sort_by_key(|client| client.minimized);
+
+        minimized.into_iter().map(|client| client.window).collect()
+    }
+
+    pub fn minimize(&mut self, connection: &xcb::Connection, window: x::Window) -> bool {
+        if let Some(client) = self
+            .tags
+            .iter_mut()
+            .flatten()
+            .find(|client| client.window == window && client.minimized.is_none())
+        {
+            client.minimized = Some(Instant::now());
+
+            hide(connection, client);
+
+            self.arrange(connection);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn restore(&mut self, connection: &xcb::Connection, window: x::Window) {
+        if let Some(client) = self.tags[self.tag]
+            .iter_mut()
+            .find(|client| client.window == window && client.minimized.is_some())
+        {
+            client.minimized = None;
+
+            self.arrange(connection);
+        }
+    }
+
+    pub fn last_minimized(&self) -> Option<x::Window> {
+        self.tags
+            .iter()
+            .flatten()
+            .filter(|client| client.minimized.is_some())
+            .max_by_key(|client| client.minimized)
+            .map(|client| client.window)
+    }
+
+    pub fn is_minimized(&self, window: x::Window) -> bool {
+        self.tags
+            .iter()
+            .flatten()
+            .any(|client| client.window == window && client.minimized.is_some())
+    }
+
+    pub fn hidden(&self) -> Vec<x::Window> {
+        self.tags
+            .iter()
+            .flatten()
+            .flat_map(|client| once(client).chain(client.swallowed.as_deref()))
+            .filter(|client| client.hidden)
+            .map(|client| client.window)
+            .collect()
+    }
+
+    pub fn ignore_unmap(&mut self, window: x::Window) -> bool {
+        self.tags
+            .iter_mut()
+            .flatten()
+            .find_map(|client| {
+                if client.window == window {
+                    Some(client)
+                } else {
+                    client
+                        .swallowed
+                        .as_deref_mut()
+                        .filter(|terminal| terminal.window == window)
+                }
+            })
+            .is_some_and(|client| client.ignore_unmap())
+    }
+
+    pub fn stacking(&self) -> Vec<x::Window> {
+        let clients: Vec<&Client> = self
+            .stack
+            .iter()
+            .filter_map(|window| self.client(*window))
+            .collect();
+
+        let mut stacking = Vec::with_capacity(clients.len());
+        for i in 0..=2 {
+            let in_layer: Vec<&Client> = clients
+                .iter()
+                .copied()
+                .filter(|client| layer(client) == i)
+                .collect();
+
+            // Transients of a client in another layer start their own chain in this one
+            for client in &in_layer {
+                if client
+                    .transient_for
+                    .is_none_or(|parent| !in_layer.iter().any(|client| client.window == parent))
+                {
+                    push_with_transients(&mut stacking, &in_layer, client);
+                }
+            }
+        }
+
+        stacking
+    }
+
+    pub fn scratchpad(&self, name: &str) -> Option<(usize, &Client)> {
+        self.tags.iter().enumerate().find_map(|(i, clients)| {
+            clients
+                .iter()
+                .find(|client| client.scratchpad == Some(name))
+                .map(|client| (i, client))
+        })
+    }
+
     pub fn contains(&self, x: i16, y: i16) -> bool {
         x >= self.x
             && x < self.x + self.width as i16
@@ -67,6 +227,13 @@ impl Monitor {
             && y < self.y + self.height as i16
     }
 
+    pub fn overlaps(&self, x: i16, y: i16, width: u16, height: u16) -> bool {
+        x < self.x + self.width as i16
+            && x + width as i16 > self.x
+            && y < self.y + self.height as i16
+            && y + height as i16 > self.y
+    }
+
     pub fn update(
         &mut self,
         connection: &xcb::Connection,
@@ -151,10 +318,7 @@ impl Monitor {
 
                 connection.send_request(&x::ConfigureWindow {
                     window,
-                    value_list: &[
-                        x::ConfigWindow::BorderWidth(0),
-                        x::ConfigWindow::StackMode(x::StackMode::Above),
-                    ],
+                    value_list: &[x::ConfigWindow::BorderWidth(0)],
                 });
 
                 resize(connection, client, self.x, self.y, self.width, self.height);
@@ -184,6 +348,8 @@ impl Monitor {
                     self.arrange(connection);
                 }
             }
+
+            self.restack(connection);
         }
     }
 
@@ -201,17 +367,25 @@ impl Monitor {
 
                 connection.send_request(&x::ConfigureWindow {
                     window,
-                    value_list: &[
-                        x::ConfigWindow::BorderWidth(0),
-                        x::ConfigWindow::StackMode(x::StackMode::Above),
-                    ],
+                    value_list: &[x::ConfigWindow::BorderWidth(0)],
                 });
 
                 resize(connection, client, self.x, self.y, self.width, self.height);
+
+                self.restack(connection);
             }
         }
     }
 
+    pub fn raise(&mut self, connection: &xcb::Connection, window: x::Window) {
+        if let Some(position) = self.stack.iter().position(|w| *w == window) {
+            let window = self.stack.remove(position);
+            self.stack.push(window);
+
+            self.restack(connection);
+        }
+    }
+
     pub fn toggle_floating(&mut self, connection: &xcb::Connection, window: x::Window) {
         if let Some(client) = self
             .tags
@@ -256,7 +430,7 @@ impl Monitor {
         if tag == self.tag {
             false
         } else {
-            for client in &self.tags[self.tag] {
+            for client in &mut self.tags[self.tag] {
                 hide(connection, client);
             }
 
@@ -276,9 +450,9 @@ impl Monitor {
                 .iter()
                 .position(|client| client.window == window)
             {
-                let client = self.tags[self.tag].remove(position);
+                let mut client = self.tags[self.tag].remove(position);
 
-                hide(connection, &client);
+                hide(connection, &mut client);
 
                 self.tags[tag].push(client);
 
@@ -289,55 +463,104 @@ impl Monitor {
         }
     }
 
-    pub fn map(&mut self, connection: &xcb::Connection, mut client: Client) {
-        if client.fullscreen {
-            connection.send_request(&x::ConfigureWindow {
-                window: client.window,
-                value_list: &[x::ConfigWindow::BorderWidth(0)],
-            });
+    pub fn map(&mut self, connection: &xcb::Connection, mut client: Client, tag: usize) {
+        self.prepare(connection, &mut client);
 
-            client.old_floating = client.floating;
-            client.floating = true;
+        self.stack.push(client.window);
 
-            resize(
-                connection,
-                &mut client,
-                self.x,
-                self.y,
-                self.width,
-                self.height,
-            );
+        if tag == self.tag {
+            self.tags[tag].push(client);
+            self.arrange(connection);
         } else {
-            connection.send_request(&x::ConfigureWindow {
-                window: client.window,
-                value_list: &[x::ConfigWindow::BorderWidth(config::BORDER_WIDTH as u32)],
-            });
+            hide_unmapped(connection, &mut client);
+
+            self.tags[tag].push(client);
         }
 
-        if client.floating {
-            let x = client.x;
-            let y = client.y;
-            let width = client.width;
-            let height = client.height;
+        self.draw_tags();
+    }
 
-            resize(connection, &mut client, x, y, width, height);
-        }
+    pub fn terminals(&self) -> Vec<(x::Window, u32)> {
+        self.tags
+            .iter()
+            .flatten()
+            .filter(|client| client.terminal)
+            .filter_map(|client| client.pid.map(|pid| (client.window, pid)))
+            .collect()
+    }
 
-        self.tags[self.tag].push(client);
-        self.arrange(connection);
+    pub fn swallow(
+        &mut self,
+        connection: &xcb::Connection,
+        terminal: x::Window,
+        mut client: Client,
+    ) {
+        if let Some((tag, position)) = self.tags.iter().enumerate().find_map(|(i, clients)| {
+            clients
+                .iter()
+                .position(|client| client.window == terminal)
+                .map(|position| (i, position))
+        }) {
+            let mut terminal = self.tags[tag].remove(position);
 
-        self.draw_tags();
+            client.x = terminal.x;
+            client.y = terminal.y;
+            client.width = terminal.width;
+            client.height = terminal.height;
+            client.floating = terminal.floating;
+
+            self.prepare(connection, &mut client);
+
+            hide(connection, &mut terminal);
+
+            if tag != self.tag {
+                hide_unmapped(connection, &mut client);
+            }
+
+            for window in &mut self.stack {
+                if *window == terminal.window {
+                    *window = client.window;
+                }
+            }
+
+            client.swallowed = Some(Box::new(terminal));
+            self.tags[tag].insert(position, client);
+
+            self.arrange(connection);
+            self.draw_tags();
+        }
     }
 
     pub fn unmap(&mut self, connection: &xcb::Connection, window: x::Window) {
         for tag in self.tags.iter_mut() {
             if let Some(position) = tag.iter().position(|client| client.window == window) {
-                tag.remove(position);
+                if let Some(terminal) = tag.remove(position).swallowed {
+                    for stacked in &mut self.stack {
+                        if *stacked == window {
+                            *stacked = terminal.window;
+                        }
+                    }
+
+                    tag.insert(position, *terminal);
+                } else {
+                    self.stack.retain(|w| *w != window);
+                }
 
                 self.draw_tags();
 
                 break;
             }
+
+            if let Some(client) = tag.iter_mut().find(|client| {
+                client
+                    .swallowed
+                    .as_ref()
+                    .is_some_and(|terminal| terminal.window == window)
+            }) {
+                client.swallowed = None;
+
+                break;
+            }
         }
 
         self.arrange(connection);
@@ -354,6 +577,8 @@ impl Monitor {
         }
 
         if client.is_some() {
+            self.stack.retain(|w| *w != window);
+
             self.arrange(connection);
             self.draw_tags();
         }
@@ -384,6 +609,42 @@ impl Monitor {
             resize(connection, &mut client, x, y, width, height);
         }
 
+        self.stack.push(client.window);
+        self.tags[self.tag].push(client);
+
+        self.arrange(connection);
+        self.draw_tags();
+    }
+
+    pub fn center(&self, client: &mut Client) {
+        let (x, y, width, height) = self.work_area();
+
+        client.x = x + (width as i16 - client.width as i16 - 2 * config::BORDER_WIDTH as i16) / 2;
+        client.y = y + (height as i16 - client.height as i16 - 2 * config::BORDER_WIDTH as i16) / 2;
+    }
+
+    pub fn stash(&mut self, connection: &xcb::Connection, window: x::Window) -> Option<Client> {
+        let mut client = self.remove(connection, window)?;
+
+        hide(connection, &mut client);
+
+        Some(client)
+    }
+
+    pub fn unstash(&mut self, connection: &xcb::Connection, mut client: Client) {
+        client.floating = true;
+        client.minimized = None;
+
+        self.center(&mut client);
+
+        let x = client.x;
+        let y = client.y;
+        let width = client.width;
+        let height = client.height;
+
+        resize(connection, &mut client, x, y, width, height);
+
+        self.stack.push(client.window);
         self.tags[self.tag].push(client);
 
         self.arrange(connection);
@@ -434,8 +695,219 @@ impl Monitor {
                 return;
             }
 
-            client.width += x_delta as u16;
-            client.height += y_delta as u16;
+            client.width =
+                (client.width as i32 + x_delta as i32).max(config::MIN_SIZE as i32) as u16;
+            client.height =
+                (client.height as i32 + y_delta as i32).max(config::MIN_SIZE as i32) as u16;
+
+            resize(
+                connection,
+                client,
+                client.x,
+                client.y,
+                client.width,
+                client.height,
+            );
+        }
+    }
+
+    pub fn resize_edges(
+        &mut self,
+        connection: &xcb::Connection,
+        window: x::Window,
+        x: i16,
+        y: i16,
+        edges: Edges,
+    ) {
+        if let Some(client) = self.tags[self.tag]
+            .iter_mut()
+            .find(|client| client.window == window)
+        {
+            if client.fullscreen {
+                return;
+            }
+
+            let border = 2 * config::BORDER_WIDTH as i32;
+            let right = client.x as i32 + client.width as i32 + border;
+            let bottom = client.y as i32 + client.height as i32 + border;
+
+            let width = match edges.left {
+                Some(true) => right - x as i32 - border,
+                Some(false) => x as i32 - client.x as i32 - border + 1,
+                None => client.width as i32,
+            }
+            .max(config::MIN_SIZE as i32);
+            let height = match edges.top {
+                Some(true) => bottom - y as i32 - border,
+                Some(false) => y as i32 - client.y as i32 - border + 1,
+                None => client.height as i32,
+            }
+            .max(config::MIN_SIZE as i32);
+
+            let x = if edges.left == Some(true) {
+                (right - width - border) as i16
+            } else {
+                client.x
+            };
+            let y = if edges.top == Some(true) {
+                (bottom - height - border) as i16
+            } else {
+                client.y
+            };
+
+            resize(connection, client, x, y, width as u16, height as u16);
+        }
+    }
+
+    pub fn tiled(&self) -> usize {
+        self.tags[self.tag]
+            .iter()
+            .filter(|client| !client.floating && client.minimized.is_none())
+            .count()
+    }
+
+    pub fn main_split(&self) -> i16 {
+        self.x
+            + (self.main_factor * self.width as f64) as i16
+            + config::MARGIN as i16
+            + 2 * config::BORDER_WIDTH as i16
+    }
+
+    pub fn resize_main(&mut self, connection: &xcb::Connection, x: i16) {
+        let main_width = x - self.x - config::MARGIN as i16 - 2 * config::BORDER_WIDTH as i16;
+
+        self.main_factor = (main_width as f64 / self.width as f64).clamp(0.1, 0.9);
+
+        self.arrange(connection);
+    }
+
+    pub fn edges(&self, window: x::Window) -> (Vec<i16>, Vec<i16>) {
+        let mut x_edges = vec![self.x, self.x + self.width as i16];
+        let mut y_edges = vec![
+            self.y + config::BAR_HEIGHT as i16,
+            self.y + self.height as i16,
+        ];
+
+        for client in self.tags[self.tag].iter().filter(|client| {
+            client.window != window
+                && client.floating
+                && !client.fullscreen
+                && client.minimized.is_none()
+        }) {
+            x_edges.push(client.x);
+            x_edges.push(client.x + (client.width + 2 * config::BORDER_WIDTH) as i16);
+            y_edges.push(client.y);
+            y_edges.push(client.y + (client.height + 2 * config::BORDER_WIDTH) as i16);
+        }
+
+        (x_edges, y_edges)
+    }
+
+    pub fn snap_half(
+        &mut self,
+        connection: &xcb::Connection,
+        window: x::Window,
+        direction: Direction,
+    ) {
+        let (x, y, width, height) = self.work_area();
+
+        if let Some(client) = self.tags[self.tag]
+            .iter_mut()
+            .find(|client| client.window == window && client.floating && !client.fullscreen)
+        {
+            let margin = config::MARGIN as i16;
+            let border = 2 * config::BORDER_WIDTH;
+
+            let (x, y, width, height) = match direction {
+                Direction::Left => (x, y, width / 2, height),
+                Direction::Right => (x + (width / 2) as i16, y, width - width / 2, height),
+                Direction::Top => (x, y, width, height / 2),
+                Direction::Bottom => (x, y + (height / 2) as i16, width, height - height / 2),
+            };
+
+            resize(
+                connection,
+                client,
+                x + margin,
+                y + margin,
+                width.saturating_sub(2 * config::MARGIN + border),
+                height.saturating_sub(2 * config::MARGIN + border),
+            );
+        }
+    }
+
+    pub fn move_floating(
+        &mut self,
+        connection: &xcb::Connection,
+        window: x::Window,
+        x_delta: i16,
+        y_delta: i16,
+    ) {
+        if self.is_floating(window) {
+            self.drag(connection, window, x_delta, y_delta);
+            self.constrain(connection, window);
+        }
+    }
+
+    pub fn resize_floating(
+        &mut self,
+        connection: &xcb::Connection,
+        window: x::Window,
+        width_delta: i16,
+        height_delta: i16,
+    ) {
+        if self.is_floating(window) {
+            self.resize(connection, window, width_delta, height_delta);
+            self.constrain(connection, window);
+        }
+    }
+
+    pub fn center_floating(&mut self, connection: &xcb::Connection, window: x::Window) {
+        let (x, y, width, height) = self.work_area();
+
+        if let Some(client) = self.tags[self.tag]
+            .iter_mut()
+            .find(|client| client.window == window && client.floating && !client.fullscreen)
+        {
+            let outer_width = (client.width + 2 * config::BORDER_WIDTH) as i16;
+            let outer_height = (client.height + 2 * config::BORDER_WIDTH) as i16;
+
+            resize(
+                connection,
+                client,
+                x + (width as i16 - outer_width) / 2,
+                y + (height as i16 - outer_height) / 2,
+                client.width,
+                client.height,
+            );
+        }
+    }
+
+    pub fn snap_floating(
+        &mut self,
+        connection: &xcb::Connection,
+        window: x::Window,
+        direction: Direction,
+    ) {
+        let (x, y, width, height) = self.work_area();
+
+        if let Some(client) = self.tags[self.tag]
+            .iter_mut()
+            .find(|client| client.window == window && client.floating && !client.fullscreen)
+        {
+            let outer_width = (client.width + 2 * config::BORDER_WIDTH) as i16;
+            let outer_height = (client.height + 2 * config::BORDER_WIDTH) as i16;
+
+            match direction {
+                Direction::Left => client.x = x + config::MARGIN as i16,
+                Direction::Right => {
+                    client.x = x + width as i16 - outer_width - config::MARGIN as i16
+                }
+                Direction::Top => client.y = y + config::MARGIN as i16,
+                Direction::Bottom => {
+                    client.y = y + height as i16 - outer_height - config::MARGIN as i16
+                }
+            }
 
             resize(
                 connection,
@@ -451,6 +923,8 @@ impl Monitor {
     pub fn transfer(self, connection: &xcb::Connection, monitor: &mut Self) {
         self.bar.clean(connection);
 
+        monitor.stack.extend(self.stack);
+
         for tag in self.tags {
             for mut client in tag {
                 if client.floating {
@@ -478,8 +952,16 @@ impl Monitor {
         monitor.draw_tags();
     }
 
-    pub fn draw_status(&self, name: &str, status: &str) {
-        self.bar.draw_status(name, status)
+    pub fn bar_click(&self, window: x::Window, x: i16) -> Option<(Click, Option<usize>)> {
+        if window == self.bar.window() {
+            Some(self.bar.click(x))
+        } else {
+            None
+        }
+    }
+
+    pub fn draw_status(&self, mode: Option<&str>, name: &str, minimized: &[String], status: &str) {
+        self.bar.draw_status(mode, name, minimized, status)
     }
 
     pub fn configure_request(
@@ -496,7 +978,7 @@ impl Monitor {
             .flatten()
             .find(|client| client.window == window)
         {
-            if client.floating {
+            if client.floating && !client.hidden {
                 if value_mask.intersects(x::ConfigWindowMask::WIDTH) {
                     client.width = width;
                 }
@@ -523,6 +1005,132 @@ impl Monitor {
         }
     }
 
+    pub fn set_urgent(&mut self, window: x::Window, urgent: bool) -> bool {
+        if let Some(client) = self
+            .tags
+            .iter_mut()
+            .flatten()
+            .find(|client| client.window == window)
+        {
+            if client.urgent.is_some() != urgent {
+                client.urgent = if urgent { Some(Instant::now()) } else { None };
+
+                self.draw_tags();
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn set_size_hints(
+        &mut self,
+        connection: &xcb::Connection,
+        window: x::Window,
+        size_hints: SizeHints,
+    ) {
+        if let Some(client) = self
+            .tags
+            .iter_mut()
+            .flatten()
+            .find(|client| client.window == window)
+        {
+            client.size_hints = size_hints;
+
+            if client.floating && !client.fullscreen {
+                let x = client.x;
+                let y = client.y;
+                let width = client.width;
+                let height = client.height;
+
+                resize(connection, client, x, y, width, height);
+            } else if config::RESIZE_HINTS {
+                self.arrange(connection);
+            }
+        }
+    }
+
+    pub fn urgent(&self) -> Option<(Instant, usize, x::Window)> {
+        self.tags
+            .iter()
+            .enumerate()
+            .flat_map(|(i, clients)| {
+                clients
+                    .iter()
+                    .filter_map(move |client| client.urgent.map(|time| (time, i, client.window)))
+            })
+            .min_by_key(|(time, _, _)| *time)
+    }
+
+    fn work_area(&self) -> (i16, i16, u16, u16) {
+        (
+            self.x,
+            self.y + config::BAR_HEIGHT as i16,
+            self.width,
+            self.height - config::BAR_HEIGHT,
+        )
+    }
+
+    fn is_floating(&self, window: x::Window) -> bool {
+        self.tags[self.tag]
+            .iter()
+            .any(|client| client.window == window && client.floating && !client.fullscreen)
+    }
+
+    fn constrain(&mut self, connection: &xcb::Connection, window: x::Window) {
+        let (area_x, area_y, area_width, area_height) = self.work_area();
+
+        if let Some(client) = self.tags[self.tag]
+            .iter_mut()
+            .find(|client| client.window == window)
+        {
+            let border = 2 * config::BORDER_WIDTH;
+
+            let width = client.width.min(area_width.saturating_sub(border).max(1));
+            let height = client.height.min(area_height.saturating_sub(border).max(1));
+
+            let x = client
+                .x
+                .min(area_x + area_width as i16 - (width + border) as i16)
+                .max(area_x);
+            let y = client
+                .y
+                .min(area_y + area_height as i16 - (height + border) as i16)
+                .max(area_y);
+
+            resize(connection, client, x, y, width, height);
+        }
+    }
+
+    fn prepare(&self, connection: &xcb::Connection, client: &mut Client) {
+        if client.fullscreen {
+            connection.send_request(&x::ConfigureWindow {
+                window: client.window,
+                value_list: &[x::ConfigWindow::BorderWidth(0)],
+            });
+
+            client.old_floating = client.floating;
+            client.floating = true;
+
+            resize(connection, client, self.x, self.y, self.width, self.height);
+        } else {
+            connection.send_request(&x::ConfigureWindow {
+                window: client.window,
+                value_list: &[x::ConfigWindow::BorderWidth(config::BORDER_WIDTH as u32)],
+            });
+        }
+
+        if client.floating {
+            let x = client.x;
+            let y = client.y;
+            let width = client.width;
+            let height = client.height;
+
+            resize(connection, client, x, y, width, height);
+        }
+    }
+
     fn draw_tags(&self) {
         self.bar.draw_tags(
             self.tag,
@@ -531,19 +1139,34 @@ impl Monitor {
                 .enumerate()
                 .filter_map(|(i, clients)| if !clients.is_empty() { Some(i) } else { None })
                 .collect(),
+            self.tags
+                .iter()
+                .enumerate()
+                .filter_map(|(i, clients)| {
+                    if clients.iter().any(|client| client.urgent.is_some()) {
+                        Some(i)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
         );
     }
 
     fn arrange(&mut self, connection: &xcb::Connection) {
-        let len = self.tags[self.tag]
-            .iter()
-            .filter(|client| !client.floating)
-            .count();
+        let len = self.tiled();
 
         let main_width = (self.main_factor * self.width as f64) as u16;
 
         let mut i = 0;
         for client in &mut self.tags[self.tag] {
+            if client.minimized.is_some() {
+                hide(connection, client);
+                continue;
+            }
+
+            show(connection, client);
+
             if client.floating {
                 connection.send_request(&x::ConfigureWindow {
                     window: client.window,
@@ -606,6 +1229,40 @@ impl Monitor {
                 i += 1;
             }
         }
+
+        self.restack(connection);
+    }
+
+    fn restack(&self, connection: &xcb::Connection) {
+        let visible: Vec<x::Window> = self
+            .stacking()
+            .into_iter()
+            .filter(|window| {
+                self.tags[self.tag]
+                    .iter()
+                    .any(|client| client.window == *window)
+            })
+            .collect();
+
+        let mut above = None;
+        for window in visible.into_iter().rev() {
+            if let Some(sibling) = above {
+                connection.send_request(&x::ConfigureWindow {
+                    window,
+                    value_list: &[
+                        x::ConfigWindow::Sibling(sibling),
+                        x::ConfigWindow::StackMode(x::StackMode::Below),
+                    ],
+                });
+            } else {
+                connection.send_request(&x::ConfigureWindow {
+                    window,
+                    value_list: &[x::ConfigWindow::StackMode(x::StackMode::Above)],
+                });
+            }
+
+            above = Some(window);
+        }
     }
 }
 
@@ -617,6 +1274,26 @@ fn resize(
     width: u16,
     height: u16,
 ) {
+    let (hinted_width, hinted_height) =
+        if !client.fullscreen && (client.floating || config::RESIZE_HINTS) {
+            client.size_hints.apply(width, height)
+        } else {
+            (width, height)
+        };
+
+    // Keep the right or bottom edge in place when only the opposite edge moves
+    let x = if x != client.x && x as i32 + width as i32 == client.x as i32 + client.width as i32 {
+        (x as i32 + width as i32 - hinted_width as i32) as i16
+    } else {
+        x
+    };
+    let y = if y != client.y && y as i32 + height as i32 == client.y as i32 + client.height as i32 {
+        (y as i32 + height as i32 - hinted_height as i32) as i16
+    } else {
+        y
+    };
+    let (width, height) = (hinted_width, hinted_height);
+
     client.old_x = client.x;
     client.x = x;
     client.old_y = client.y;
@@ -666,18 +1343,80 @@ fn configure(connection: &xcb::Connection, client: &Client) {
     });
 }
 
-fn hide(connection: &xcb::Connection, client: &Client) {
-    let bw = if client.fullscreen {
+fn layer(client: &Client) -> u8 {
+    if client.fullscreen {
+        2
+    } else if client.floating {
+        1
+    } else {
         0
+    }
+}
+
+fn push_with_transients(stacking: &mut Vec<x::Window>, clients: &[&Client], client: &Client) {
+    if stacking.contains(&client.window) {
+        return;
+    }
+
+    stacking.push(client.window);
+
+    for transient in clients
+        .iter()
+        .filter(|transient| transient.transient_for == Some(client.window))
+    {
+        push_with_transients(stacking, clients, transient);
+    }
+}
+
+fn hide(connection: &xcb::Connection, client: &mut Client) {
+    if client.hidden {
+        return;
+    }
+
+    client.hidden = true;
+
+    if config::HIDE_BY_UNMAP {
+        client.ignore_unmap += 1;
+
+        connection.send_request(&x::UnmapWindow {
+            window: client.window,
+        });
     } else {
-        config::BORDER_WIDTH
-    };
+        let bw = if client.fullscreen {
+            0
+        } else {
+            config::BORDER_WIDTH
+        };
+
+        connection.send_request(&x::ConfigureWindow {
+            window: client.window,
+            value_list: &[
+                x::ConfigWindow::X((client.width as i32 + 2 * bw as i32) * -2),
+                x::ConfigWindow::Y(client.y as i32),
+            ],
+        });
+    }
+}
 
-    connection.send_request(&x::ConfigureWindow {
-        window: client.window,
-        value_list: &[
-            x::ConfigWindow::X((client.width as i32 + 2 * bw as i32) * -2),
-            x::ConfigWindow::Y(client.y as i32),
-        ],
-    });
+fn hide_unmapped(connection: &xcb::Connection, client: &mut Client) {
+    // The window isn't mapped yet, so there is nothing to unmap
+    if config::HIDE_BY_UNMAP {
+        client.hidden = true;
+    } else {
+        hide(connection, client);
+    }
+}
+
+fn show(connection: &xcb::Connection, client: &mut Client) {
+    if !client.hidden {
+        return;
+    }
+
+    client.hidden = false;
+
+    if config::HIDE_BY_UNMAP {
+        connection.send_request(&x::MapWindow {
+            window: client.window,
+        });
+    }
 }
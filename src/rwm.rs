@@ -1,17 +1,28 @@
 use std::{
-    collections::HashMap,
-    process::{exit, Child, Command},
+    collections::{HashMap, HashSet},
+    env, mem,
+    os::{
+        fd::{AsRawFd, RawFd},
+        unix::process::{CommandExt, ExitStatusExt},
+    },
+    path::PathBuf,
+    process::{exit, Child, Command, ExitStatus},
+    thread,
+    time::{Duration, Instant},
 };
 
-use xcb::{x, xinerama, Xid};
+use xcb::{x, xinerama, xinput, xkb, Xid};
 
 use crate::{
-    buttons::ButtonCombo,
+    binding::{self, Binding, ParseError},
+    buttons::{ButtonCombo, Click},
     client::Client,
     config,
     cursor::Cursors,
-    keys::{KeyCombo, Keymap},
-    monitor::Monitor,
+    hints::{SizeHints, WmHints},
+    keys::{self, KeyCombo, KeyEvent, Keymap},
+    modes::Mode,
+    monitor::{Direction, Edges, Monitor},
 };
 
 atoms!(
@@ -20,44 +31,88 @@ atoms!(
     WM_PROTOCOLS,
     WM_DELETE_WINDOW,
     WM_TAKE_FOCUS,
+    WM_CHANGE_STATE,
     _NET_WM_NAME,
+    _NET_WM_PID,
     _NET_SUPPORTING_WM_CHECK,
     _NET_CLIENT_LIST,
+    _NET_CLIENT_LIST_STACKING,
     _NET_WM_WINDOW_TYPE,
     _NET_WM_WINDOW_TYPE_DIALOG,
     _NET_WM_STATE,
     _NET_WM_STATE_FULLSCREEN,
+    _NET_WM_STATE_DEMANDS_ATTENTION,
+    _NET_WM_STATE_HIDDEN,
     _NET_ACTIVE_WINDOW,
     _NET_SUPPORTED,
+    _RWM_AUTOSTARTED,
+    _RWM_COMMAND,
 );
 
+const NORMAL_STATE: u32 = 1;
+const ICONIC_STATE: u32 = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub enum FocusMode {
+    Sloppy,
+    Click,
+    Delayed(u32),
+}
+
+#[derive(Clone)]
+enum Action {
+    Function(fn(&mut Rwm)),
+    Shell(String),
+}
+
 #[derive(Debug)]
 enum State {
     Dragging(x::Window, i16, i16, u32),
-    Resizing(x::Window, i16, i16, u32),
+    Resizing(x::Window, Edges, u32),
+    ResizingMain(usize, u32),
     None,
 }
 
 pub struct Rwm {
     root: x::Window,
     connection: xcb::Connection,
+    display: String,
     atoms: [x::Atom; ATOMS.len()],
     keymap: Keymap,
     monitors: Vec<Monitor>,
     monitor: usize,
     focused: Option<x::Window>,
-    keys: HashMap<KeyCombo, fn(&mut Self)>,
-    buttons: HashMap<ButtonCombo, fn(&mut Self)>,
+    keys: HashMap<KeyCombo, Action>,
+    buttons: HashMap<ButtonCombo, Action>,
     state: State,
     border_color: u32,
     border_hl_color: u32,
     cursors: Cursors,
     children: HashMap<u32, Child>,
+    signal_fd: RawFd,
+    time: x::Timestamp,
+    client_list: Vec<x::Window>,
+    client_list_stacking: Vec<x::Window>,
+    hidden: HashSet<x::Window>,
+    scratchpads: HashMap<&'static str, Client>,
+    pending_scratchpads: HashMap<&'static str, (u32, Instant)>,
+    focus_mode: FocusMode,
+    hover: Option<(x::Window, Instant)>,
+    clicked_tag: Option<usize>,
+    mode: Option<Mode>,
+    mode_timeout: Option<Instant>,
+    quit_timeout: Option<Instant>,
+    tap: Option<x::Keycode>,
 }
 
 impl Rwm {
     pub fn new() -> Self {
-        let (connection, _) = xcb::Connection::connect(None).unwrap();
+        let (connection, _) = xcb::Connection::connect_with_extensions(
+            None,
+            &[],
+            &[xcb::Extension::Xkb, xcb::Extension::Input],
+        )
+        .unwrap();
 
         let setup = connection.get_setup();
 
@@ -70,10 +125,6 @@ impl Rwm {
                 name: atom.as_bytes(),
             })
         });
-        let keyboard_mapping_cookie = connection.send_request(&x::GetKeyboardMapping {
-            first_keycode: setup.min_keycode(),
-            count: setup.max_keycode() - setup.min_keycode() + 1,
-        });
         let border_color_cookie = connection.send_request(&x::AllocColor {
             cmap: screen.default_colormap(),
             red: (config::BORDER_COLOR >> 16) as u16 * 257,
@@ -90,12 +141,7 @@ impl Rwm {
         let atoms =
             intern_atom_cookies.map(|cookie| connection.wait_for_reply(cookie).unwrap().atom());
 
-        let keyboard_mapping = connection.wait_for_reply(keyboard_mapping_cookie).unwrap();
-        let keymap = Keymap::new(
-            keyboard_mapping.keysyms().to_vec(),
-            setup.min_keycode(),
-            keyboard_mapping.keysyms_per_keycode(),
-        );
+        let keymap = get_keymap(&connection).unwrap();
 
         let border_color = connection
             .wait_for_reply(border_color_cookie)
@@ -113,27 +159,84 @@ impl Rwm {
         Rwm {
             root,
             connection,
+            display: env::var("DISPLAY").unwrap_or_default(),
             atoms,
             keymap,
             monitors: Vec::new(),
             monitor: 0,
             focused: None,
-            keys: HashMap::from_iter(config::KEYS),
-            buttons: HashMap::from_iter(config::BUTTONS),
+            keys: HashMap::from_iter(
+                config::RELEASE_KEYS
+                    .map(|(key_combo, function)| (key_combo, Action::Function(function))),
+            ),
+            buttons: HashMap::from_iter(
+                config::BUTTONS
+                    .map(|(button_combo, function)| (button_combo, Action::Function(function))),
+            ),
             state: State::None,
             border_color,
             border_hl_color,
             cursors,
             children: HashMap::new(),
+            signal_fd: signal_fd(),
+            time: x::CURRENT_TIME,
+            client_list: Vec::new(),
+            client_list_stacking: Vec::new(),
+            hidden: HashSet::new(),
+            scratchpads: HashMap::new(),
+            pending_scratchpads: HashMap::new(),
+            focus_mode: config::FOCUS_MODE,
+            hover: None,
+            clicked_tag: None,
+            mode: None,
+            mode_timeout: None,
+            quit_timeout: None,
+            tap: None,
         }
     }
 
     pub fn spawn(&mut self, command: &str, args: &[&str]) {
-        let child = Command::new(command)
-            .args(args)
-            .spawn()
-            .unwrap_or_else(|_| panic!("Command {} with args {:?} couldn't be run", command, args));
-        self.children.insert(child.id(), child);
+        self.spawn_child(command, args);
+    }
+
+    pub fn shell(&mut self, command: &str) {
+        self.spawn("sh", &["-c", command]);
+    }
+
+    pub fn autostart(&mut self) {
+        let autostarted = self
+            .get_property(self.root, self.atoms[_RWM_AUTOSTARTED], x::ATOM_CARDINAL)
+            .is_ok_and(|reply| !reply.value::<u32>().is_empty());
+
+        if let Some(directory) = config_directory() {
+            for script in ["autostart_blocking", "autostart"].map(|name| directory.join(name)) {
+                if script.is_file() {
+                    if let Err(err) = self.command(&script.to_string_lossy(), &[]).status() {
+                        println!("{} couldn't be run: {}", script.display(), err);
+                    }
+                }
+            }
+        }
+
+        for autostart in config::AUTOSTART
+            .iter()
+            .filter(|autostart| !(autostart.once() && autostarted))
+        {
+            if let Some((command, args)) = autostart.command().split_first() {
+                self.spawn(command, args);
+            }
+        }
+
+        // Root window properties outlive rwm, so restarts within the same X session
+        // skip the entries that should only run once.
+        self.connection.send_request(&x::ChangeProperty {
+            mode: x::PropMode::Replace,
+            window: self.root,
+            property: self.atoms[_RWM_AUTOSTARTED],
+            r#type: x::ATOM_CARDINAL,
+            data: &[1u32],
+        });
+        let _ = self.connection.flush();
     }
 
     pub fn kill(&mut self) {
@@ -164,6 +267,35 @@ impl Rwm {
         }
     }
 
+    pub fn move_floating(&mut self, x_delta: i16, y_delta: i16) {
+        if let Some(window) = self.focused {
+            self.monitors[self.monitor].move_floating(&self.connection, window, x_delta, y_delta);
+        }
+    }
+
+    pub fn resize_floating(&mut self, width_delta: i16, height_delta: i16) {
+        if let Some(window) = self.focused {
+            self.monitors[self.monitor].resize_floating(
+                &self.connection,
+                window,
+                width_delta,
+                height_delta,
+            );
+        }
+    }
+
+    pub fn center_floating(&mut self) {
+        if let Some(window) = self.focused {
+            self.monitors[self.monitor].center_floating(&self.connection, window);
+        }
+    }
+
+    pub fn snap_floating(&mut self, direction: Direction) {
+        if let Some(window) = self.focused {
+            self.monitors[self.monitor].snap_floating(&self.connection, window, direction);
+        }
+    }
+
     pub fn main_factor(&mut self, factor: f64) {
         self.monitors[self.monitor].main_factor(&self.connection, factor);
     }
@@ -184,31 +316,185 @@ impl Rwm {
         }
     }
 
+    pub fn view_clicked(&mut self) {
+        if let Some(tag) = self.clicked_tag {
+            self.view(tag);
+        }
+    }
+
+    pub fn tag_clicked(&mut self) {
+        if let Some(tag) = self.clicked_tag {
+            self.tag(tag);
+        }
+    }
+
+    pub fn cycle_view(&mut self, direction: isize) {
+        let tag = (self.monitors[self.monitor].selected_tag() as isize + direction)
+            .rem_euclid(config::TAGS.len() as isize);
+
+        self.view(tag as usize);
+    }
+
     pub fn tagmon(&mut self) {
         if self.monitors.len() == 1 {
             return;
         }
 
         if let Some(window) = self.focused {
-            if let Some(client) = self.monitors[self.monitor].remove(&self.connection, window) {
+            let next_mon = (self.monitor + 1) % self.monitors.len();
+            self.send_to_monitor(window, self.monitor, next_mon);
+        }
+    }
+
+    pub fn focus_urgent(&mut self) {
+        if let Some((monitor, (_, tag, window))) = self
+            .monitors
+            .iter()
+            .enumerate()
+            .filter_map(|(i, monitor)| monitor.urgent().map(|urgent| (i, urgent)))
+            .min_by_key(|(_, (time, _, _))| *time)
+        {
+            if self.monitor != monitor {
+                self.monitor = monitor;
                 self.focus(None);
+            }
+
+            self.view(tag);
+            self.focus(Some(window));
+            self.draw_status();
+        }
+    }
+
+    pub fn minimize(&mut self) {
+        if let Some(window) = self.focused {
+            self.minimize_window(window);
+        }
+    }
+
+    pub fn restore_last(&mut self) {
+        if let Some(window) = self.monitors[self.monitor].last_minimized() {
+            self.restore_window(window);
+        }
+    }
+
+    pub fn toggle_scratchpad(&mut self, name: &'static str) {
+        // A scratchpad whose window never showed up can be spawned again after the timeout
+        if self
+            .pending_scratchpads
+            .get(name)
+            .is_some_and(|(_, timeout)| Instant::now() < *timeout)
+        {
+            return;
+        }
+
+        if let Some(client) = self.scratchpads.remove(name) {
+            let window = client.window;
+
+            self.monitors[self.monitor].unstash(&self.connection, client);
+            self.focus(Some(window));
+            self.draw_status();
+        } else if let Some((monitor, window, visible)) =
+            self.monitors.iter().enumerate().find_map(|(i, monitor)| {
+                monitor.scratchpad(name).map(|(tag, client)| {
+                    (
+                        i,
+                        client.window,
+                        i == self.monitor
+                            && tag == monitor.selected_tag()
+                            && client.minimized.is_none(),
+                    )
+                })
+            })
+        {
+            if visible {
+                if let Some(client) = self.monitors[monitor].stash(&self.connection, window) {
+                    if Some(window) == self.focused {
+                        self.focus(None);
+                    }
+
+                    self.scratchpads.insert(name, client);
+                    self.draw_status();
+                }
+            } else if let Some(client) = self.monitors[monitor].remove(&self.connection, window) {
+                self.monitors[self.monitor].unstash(&self.connection, client);
+                self.focus(Some(window));
+                self.draw_status();
+            }
+        } else if let Some((command, args)) = config::SCRATCHPADS
+            .iter()
+            .find(|scratchpad| scratchpad.name() == name)
+            .and_then(|scratchpad| scratchpad.command().split_first())
+        {
+            if let Some(pid) = self.spawn_child(command, args) {
+                self.pending_scratchpads.insert(
+                    name,
+                    (
+                        pid,
+                        Instant::now() + Duration::from_millis(config::SCRATCHPAD_TIMEOUT),
+                    ),
+                );
+            }
+        }
+    }
+
+    pub fn enter_mode(&mut self, name: &str) {
+        if let Some(mode) = config::MODES.into_iter().find(|mode| mode.name() == name) {
+            self.connection.send_request(&x::GrabKeyboard {
+                owner_events: false,
+                grab_window: self.root,
+                time: x::CURRENT_TIME,
+                pointer_mode: x::GrabMode::Async,
+                keyboard_mode: x::GrabMode::Async,
+            });
+
+            self.mode = Some(mode);
+            self.mode_timeout = Some(Instant::now() + Duration::from_millis(config::MODE_TIMEOUT));
+            self.draw_status();
+        }
+    }
+
+    pub fn exit_mode(&mut self) {
+        if self.mode.take().is_some() {
+            self.mode_timeout = None;
+
+            self.connection.send_request(&x::UngrabKeyboard {
+                time: x::CURRENT_TIME,
+            });
+
+            self.draw_status();
+        }
+    }
+
+    pub fn set_focus_mode(&mut self, focus_mode: FocusMode) {
+        self.focus_mode = focus_mode;
+        self.hover = None;
 
-                let next_mon = (self.monitor + 1) % self.monitors.len();
-                let x = self.monitors[self.monitor].x();
-                let y = self.monitors[self.monitor].y();
-                let width = self.monitors[self.monitor].width();
-                let height = self.monitors[self.monitor].height();
-                self.monitors[next_mon].add(&self.connection, client, x, y, width, height);
+        for monitor in &self.monitors {
+            for window in monitor.clients() {
+                self.grab_buttons(window, Some(window) == self.focused);
             }
         }
     }
 
     pub fn quit(&mut self) {
-        for child in self.children.values_mut() {
-            let _ = child.kill();
+        if config::CONFIRM_QUIT
+            && !self.client_list.is_empty()
+            && config::MODES
+                .iter()
+                .any(|mode| mode.name() == config::QUIT_MODE)
+        {
+            self.enter_mode(config::QUIT_MODE);
+        } else {
+            self.quit_confirmed();
+        }
+    }
+
+    pub fn quit_confirmed(&mut self) {
+        for window in self.client_list.clone() {
+            self.send_event(window, self.atoms[WM_DELETE_WINDOW]);
         }
 
-        exit(0);
+        self.quit_timeout = Some(Instant::now() + Duration::from_millis(config::QUIT_TIMEOUT));
     }
 
     pub fn drag(&mut self) {
@@ -231,18 +517,98 @@ impl Rwm {
                 });
 
                 self.monitors[self.monitor].set_floating(&self.connection, window);
-                self.state =
-                    State::Dragging(window, pointer_reply.root_x(), pointer_reply.root_y(), 0);
+
+                if let Some(client) = self.monitors[self.monitor].client(window) {
+                    self.state = State::Dragging(
+                        window,
+                        pointer_reply.root_x() - client.x,
+                        pointer_reply.root_y() - client.y,
+                        0,
+                    );
+                }
             }
         }
     }
 
     pub fn resize(&mut self) {
         if let Some(window) = self.focused {
+            let client = match self.monitors[self.monitor].client(window) {
+                Some(client) if !client.fullscreen => client,
+                _ => return,
+            };
+
+            // With a single tiled client there is no split to move
+            if !client.floating && self.monitors[self.monitor].tiled() < 2 {
+                return;
+            }
+
             if let Ok(pointer_reply) = self.connection.wait_for_reply(
                 self.connection
                     .send_request(&x::QueryPointer { window: self.root }),
             ) {
+                if client.floating {
+                    let x = pointer_reply.root_x() - client.x;
+                    let y = pointer_reply.root_y() - client.y;
+
+                    // The middle third of a side only resizes along one axis
+                    let third = |offset: i16, size: u16| {
+                        if offset < size as i16 / 3 {
+                            Some(true)
+                        } else if offset >= size as i16 - size as i16 / 3 {
+                            Some(false)
+                        } else {
+                            None
+                        }
+                    };
+
+                    let mut edges = Edges {
+                        left: third(x, client.width),
+                        top: third(y, client.height),
+                    };
+
+                    if edges.left.is_none() && edges.top.is_none() {
+                        edges = Edges {
+                            left: Some(x < (client.width / 2) as i16),
+                            top: Some(y < (client.height / 2) as i16),
+                        };
+                    }
+
+                    let bw = config::BORDER_WIDTH as i16;
+                    self.connection.send_request(&x::WarpPointer {
+                        src_window: x::WINDOW_NONE,
+                        dst_window: window,
+                        src_x: 0,
+                        src_y: 0,
+                        src_width: 0,
+                        src_height: 0,
+                        dst_x: match edges.left {
+                            Some(true) => -bw,
+                            Some(false) => client.width as i16 + bw - 1,
+                            None => x - bw,
+                        },
+                        dst_y: match edges.top {
+                            Some(true) => -bw,
+                            Some(false) => client.height as i16 + bw - 1,
+                            None => y - bw,
+                        },
+                    });
+
+                    self.state = State::Resizing(window, edges, 0);
+                } else {
+                    self.connection.send_request(&x::WarpPointer {
+                        src_window: x::WINDOW_NONE,
+                        dst_window: self.root,
+                        src_x: 0,
+                        src_y: 0,
+                        src_width: 0,
+                        src_height: 0,
+                        dst_x: self.monitors[self.monitor].main_split(),
+                        dst_y: pointer_reply.root_y(),
+                    });
+
+                    self.state = State::ResizingMain(self.monitor, 0);
+                }
+
                 self.connection.send_request(&x::GrabPointer {
                     owner_events: false,
                     grab_window: self.root,
@@ -255,10 +621,6 @@ impl Rwm {
                     cursor: self.cursors.sizing(),
                     time: x::CURRENT_TIME,
                 });
-
-                self.monitors[self.monitor].set_floating(&self.connection, window);
-                self.state =
-                    State::Resizing(window, pointer_reply.root_x(), pointer_reply.root_y(), 0);
             }
         }
     }
@@ -266,32 +628,51 @@ impl Rwm {
     pub fn setup(&mut self) {
         self.update_monitors();
 
-        for key_combo in self.keys.keys() {
-            self.connection.send_request(&x::GrabKey {
-                owner_events: true,
-                grab_window: self.root,
-                modifiers: x::ModMask::from_bits_truncate(key_combo.mask().bits()),
-                key: self.keymap.get_keycode(key_combo.key()),
-                pointer_mode: x::GrabMode::Async,
-                keyboard_mode: x::GrabMode::Async,
+        for (binding, function) in config::BINDINGS {
+            if let Err(err) = self.bind(binding, Action::Function(function)) {
+                println!("{}: {}", binding, err);
+            }
+        }
+
+        self.grab_keys();
+
+        if self
+            .connection
+            .wait_for_reply(self.connection.send_request(&xkb::UseExtension {
+                wanted_major: 1,
+                wanted_minor: 0,
+            }))
+            .is_ok_and(|reply| reply.supported())
+        {
+            self.connection.send_request(&xkb::SelectEvents {
+                device_spec: xkb::Id::UseCoreKbd as xkb::DeviceSpec,
+                affect_which: xkb::EventType::NEW_KEYBOARD_NOTIFY | xkb::EventType::MAP_NOTIFY,
+                clear: xkb::EventType::empty(),
+                select_all: xkb::EventType::NEW_KEYBOARD_NOTIFY | xkb::EventType::MAP_NOTIFY,
+                affect_map: xkb::MapPart::KEY_SYMS | xkb::MapPart::MODIFIER_MAP,
+                map: xkb::MapPart::KEY_SYMS | xkb::MapPart::MODIFIER_MAP,
+                details: &[],
             });
         }
 
-        for button_combo in self.buttons.keys() {
-            self.connection.send_request(&x::GrabButton {
-                owner_events: false,
-                grab_window: self.root,
-                event_mask: x::EventMask::NO_EVENT,
-                pointer_mode: x::GrabMode::Async,
-                keyboard_mode: x::GrabMode::Async,
-                confine_to: x::WINDOW_NONE,
-                cursor: x::CURSOR_NONE,
-                button: if button_combo.button() == 1 {
-                    x::ButtonIndex::N1
-                } else {
-                    x::ButtonIndex::N3
-                },
-                modifiers: x::ModMask::from_bits_truncate(button_combo.mask().bits()),
+        // Raw events reach the root window without a grab, so tapping a modifier doesn't
+        // swallow it for the focused client
+        if self
+            .connection
+            .wait_for_reply(self.connection.send_request(&xinput::XiQueryVersion {
+                major_version: 2,
+                minor_version: 1,
+            }))
+            .is_ok_and(|reply| (reply.major_version(), reply.minor_version()) >= (2, 1))
+        {
+            self.connection.send_request(&xinput::XiSelectEvents {
+                window: self.root,
+                masks: &[xinput::EventMaskBuf::new(
+                    xinput::Device::AllMaster,
+                    &[xinput::XiEventMask::RAW_KEY_PRESS
+                        | xinput::XiEventMask::RAW_KEY_RELEASE
+                        | xinput::XiEventMask::RAW_BUTTON_PRESS],
+                )],
             });
         }
 
@@ -302,6 +683,7 @@ impl Rwm {
                     x::EventMask::BUTTON_PRESS
                         | x::EventMask::BUTTON_RELEASE
                         | x::EventMask::KEY_PRESS
+                        | x::EventMask::KEY_RELEASE
                         | x::EventMask::POINTER_MOTION
                         | x::EventMask::PROPERTY_CHANGE
                         | x::EventMask::STRUCTURE_NOTIFY
@@ -370,173 +752,746 @@ impl Rwm {
             property: self.atoms[_NET_CLIENT_LIST],
         });
 
+        self.connection.send_request(&x::DeleteProperty {
+            window: self.root,
+            property: self.atoms[_NET_CLIENT_LIST_STACKING],
+        });
+
         self.connection.flush().unwrap();
     }
 
     pub fn run(&mut self) {
         loop {
-            match self.connection.wait_for_event() {
+            let Some(event) = self.wait_for_event() else {
+                let now = Instant::now();
+
+                if self.mode_timeout.is_some_and(|timeout| now >= timeout) {
+                    self.exit_mode();
+                }
+
+                if let Some((window, timeout)) = self.hover {
+                    if now >= timeout {
+                        self.hover = None;
+                        self.focus_hovered(window);
+                    }
+                }
+
+                if self.quit_timeout.is_some_and(|timeout| now >= timeout) {
+                    for window in &self.client_list {
+                        self.connection.send_request(&x::KillClient {
+                            resource: window.resource_id(),
+                        });
+                    }
+
+                    self.terminate();
+                }
+
+                let _ = self.connection.flush();
+                continue;
+            };
+
+            match event {
                 Ok(event) => match event {
                     xcb::Event::X(x::Event::KeyPress(event)) => self.key_press(event),
+                    xcb::Event::X(x::Event::KeyRelease(event)) => self.key_release(event),
                     xcb::Event::X(x::Event::ButtonPress(event)) => self.button_press(event),
-                    xcb::Event::X(x::Event::ButtonRelease(_)) => self.button_release(),
+                    xcb::Event::X(x::Event::ButtonRelease(event)) => self.button_release(event),
                     xcb::Event::X(x::Event::MapRequest(event)) => self.map_request(event),
-                    xcb::Event::X(x::Event::UnmapNotify(event)) => self.unmap(event.window()),
+                    xcb::Event::X(x::Event::UnmapNotify(event)) => self.unmap_notify(event),
                     xcb::Event::X(x::Event::DestroyNotify(event)) => self.unmap(event.window()),
                     xcb::Event::X(x::Event::ConfigureRequest(event)) => {
                         self.configure_request(event)
                     }
                     xcb::Event::X(x::Event::ConfigureNotify(event)) => self.configure_notify(event),
                     xcb::Event::X(x::Event::EnterNotify(event)) => self.enter_notify(event),
+                    xcb::Event::X(x::Event::LeaveNotify(event)) => self.leave_notify(event),
                     xcb::Event::X(x::Event::MotionNotify(event)) => self.motion_notify(event),
                     xcb::Event::X(x::Event::PropertyNotify(event)) => self.property_notify(event),
                     xcb::Event::X(x::Event::ClientMessage(event)) => self.client_message(event),
+                    xcb::Event::X(x::Event::MappingNotify(event)) => self.mapping_notify(event),
+                    xcb::Event::Xkb(xkb::Event::NewKeyboardNotify(_))
+                    | xcb::Event::Xkb(xkb::Event::MapNotify(_)) => self.update_keymap(),
+                    xcb::Event::Input(xinput::Event::RawKeyPress(event)) => {
+                        self.raw_key_press(event)
+                    }
+                    xcb::Event::Input(xinput::Event::RawKeyRelease(event)) => {
+                        self.raw_key_release(event)
+                    }
+                    xcb::Event::Input(xinput::Event::RawButtonPress(_)) => self.tap = None,
                     _ => {}
                 },
                 Err(err) => println!("{:?}", err),
             }
 
-            self.children
-                .retain(|_, child| child.try_wait().map_or(true, |ret| ret.is_none()));
+            self.update_client_list();
+            self.update_client_states();
+
+            if self.quit_timeout.is_some() && self.client_list.is_empty() {
+                self.terminate();
+            }
 
             let _ = self.connection.flush();
         }
     }
 
-    fn key_press(&mut self, event: x::KeyPressEvent) {
-        let key_combo = KeyCombo::new(event.state(), self.keymap.get_keysym(event.detail()));
+    fn terminate(&mut self) -> ! {
+        let _ = self.connection.flush();
 
-        if let Some(command) = self.keys.get(&key_combo) {
-            self.connection.send_request(&x::GrabKeyboard {
-                owner_events: false,
-                grab_window: self.root,
-                time: x::CURRENT_TIME,
-                pointer_mode: x::GrabMode::Async,
-                keyboard_mode: x::GrabMode::Async,
-            });
-            command(self);
-            self.connection.send_request(&x::UngrabKeyboard {
-                time: x::CURRENT_TIME,
-            });
+        // Children run in their own sessions, so signal their whole process groups.
+        for pid in self.children.keys() {
+            unsafe {
+                libc::kill(-(*pid as i32), libc::SIGTERM);
+            }
         }
-    }
 
-    fn button_press(&mut self, event: x::ButtonPressEvent) {
-        let button_combo = ButtonCombo::new(event.state(), event.detail());
+        let timeout = Instant::now() + Duration::from_millis(config::TERM_TIMEOUT);
 
-        if let Some(command) = self.buttons.get(&button_combo) {
-            command(self);
+        while !self.children.is_empty() && Instant::now() < timeout {
+            thread::sleep(Duration::from_millis(50));
+            self.reap_children();
         }
-    }
 
-    fn button_release(&mut self) {
-        self.connection.send_request(&x::UngrabPointer {
-            time: x::CURRENT_TIME,
-        });
+        for pid in self.children.keys() {
+            unsafe {
+                libc::kill(-(*pid as i32), libc::SIGKILL);
+            }
+        }
 
-        self.state = State::None
+        exit(0);
     }
 
-    fn map_request(&mut self, event: x::MapRequestEvent) {
-        self.connection.send_request(&x::ChangeWindowAttributes {
-            window: event.window(),
-            value_list: &[
-                x::Cw::BorderPixel(self.border_color),
-                x::Cw::EventMask(x::EventMask::ENTER_WINDOW | x::EventMask::PROPERTY_CHANGE),
-            ],
+    fn grab_keys(&self) {
+        self.connection.send_request(&x::UngrabKey {
+            key: x::GRAB_ANY,
+            grab_window: self.root,
+            modifiers: x::ModMask::ANY,
         });
 
-        let fixed = if let Ok(property) = self.get_property(
-            event.window(),
-            x::ATOM_WM_NORMAL_HINTS,
-            x::ATOM_WM_SIZE_HINTS,
-        ) {
-            let data: &[u32] = property.value();
-
-            if data.len() > 8 {
-                let x = data[1] as i16;
-                let y = data[2] as i16;
-                let min_width = data[5] as u16;
-                let min_height = data[6] as u16;
-                let max_width = data[7] as u16;
-                let max_height = data[8] as u16;
-
-                let fixed = min_width > 0
-                    && min_height > 0
-                    && min_width == max_width
-                    && min_height == max_height;
-
-                if fixed {
-                    self.monitors[self.monitor].map(
-                        &self.connection,
-                        Client::new(event.window(), x, y, min_width, min_height, false, true),
-                    );
+        for key_combo in self.keys.keys() {
+            if key_combo.event() == KeyEvent::Release && keys::is_modifier(key_combo.key()) {
+                continue;
+            }
 
-                    self.connection.send_request(&x::MapWindow {
-                        window: event.window(),
+            let keycodes = self.keymap.get_keycodes(key_combo.key());
+
+            if keycodes.is_empty() {
+                println!("no keycode for keysym {:#x}", key_combo.key());
+            }
+
+            for (key, mask) in keycodes {
+                for modifiers in self.keymap.lock_masks(key_combo.mask() | mask) {
+                    self.connection.send_request(&x::GrabKey {
+                        owner_events: true,
+                        grab_window: self.root,
+                        modifiers,
+                        key,
+                        pointer_mode: x::GrabMode::Async,
+                        keyboard_mode: x::GrabMode::Async,
                     });
                 }
+            }
+        }
+    }
 
-                fixed
-            } else {
-                false
+    fn update_keymap(&mut self) {
+        match get_keymap(&self.connection) {
+            Ok(keymap) => self.keymap = keymap,
+            Err(err) => {
+                println!("{:?}", err);
+                return;
             }
-        } else {
-            false
+        }
+
+        self.grab_bindings();
+    }
+
+    fn bind(&mut self, binding: &str, action: Action) -> Result<(), ParseError> {
+        match binding::parse(binding)? {
+            Binding::Key(key_combo) => self.keys.insert(key_combo, action),
+            Binding::Button(button_combo) => self.buttons.insert(button_combo, action),
         };
 
-        if !fixed {
-            let geometry_cookie = self.connection.send_request(&x::GetGeometry {
-                drawable: x::Drawable::Window(event.window()),
-            });
+        Ok(())
+    }
 
-            let fullscreen = self
-                .get_atom_property(event.window(), self.atoms[_NET_WM_STATE])
-                .contains(&self.atoms[_NET_WM_STATE_FULLSCREEN]);
-            let floating = self
-                .get_atom_property(event.window(), self.atoms[_NET_WM_WINDOW_TYPE])
-                .contains(&self.atoms[_NET_WM_WINDOW_TYPE_DIALOG]);
+    fn unbind(&mut self, binding: &str) -> Result<(), ParseError> {
+        match binding::parse(binding)? {
+            Binding::Key(key_combo) => self.keys.remove(&key_combo),
+            Binding::Button(button_combo) => self.buttons.remove(&button_combo),
+        };
 
-            if let Ok(geometry) = self.connection.wait_for_reply(geometry_cookie) {
-                self.monitors[self.monitor].map(
-                    &self.connection,
-                    Client::new(
-                        event.window(),
-                        geometry.x(),
-                        geometry.y(),
-                        geometry.width(),
-                        geometry.height(),
-                        fullscreen,
-                        floating,
-                    ),
-                );
-            } else {
-                self.monitors[self.monitor].map(
-                    &self.connection,
-                    Client::new(event.window(), 0, 0, 0, 0, fullscreen, floating),
-                );
+        Ok(())
+    }
+
+    // Handles "bind <binding> <shell command>" and "unbind <binding>" written to the root
+    // window's _RWM_COMMAND property.
+    fn ipc(&mut self, message: &str) {
+        let (command, args) = message
+            .trim()
+            .split_once(' ')
+            .unwrap_or((message.trim(), ""));
+
+        let result = match command {
+            "bind" => match args.trim().split_once(' ') {
+                Some((binding, shell)) => {
+                    self.bind(binding, Action::Shell(shell.trim().to_string()))
+                }
+                None => {
+                    println!("{}: missing command", message);
+                    return;
+                }
+            },
+            "unbind" => self.unbind(args.trim()),
+            _ => {
+                println!("unknown command {}", command);
+                return;
             }
+        };
+
+        match result {
+            Ok(()) => self.grab_bindings(),
+            Err(err) => println!("{}: {}", message, err),
         }
+    }
 
-        self.connection.send_request(&x::ChangeProperty {
-            mode: x::PropMode::Append,
-            window: self.root,
-            property: self.atoms[_NET_CLIENT_LIST],
-            r#type: x::ATOM_WINDOW,
-            data: &[event.window()],
-        });
+    fn execute(&mut self, action: Action) {
+        match action {
+            Action::Function(function) => function(self),
+            Action::Shell(command) => self.shell(&command),
+        }
+    }
 
-        self.connection.send_request(&x::ChangeProperty {
-            mode: x::PropMode::Replace,
+    fn grab_bindings(&self) {
+        self.grab_keys();
+
+        for monitor in &self.monitors {
+            for window in monitor.clients() {
+                self.grab_buttons(window, Some(window) == self.focused);
+            }
+        }
+    }
+
+    fn mapping_notify(&mut self, event: x::MappingNotifyEvent) {
+        if let x::Mapping::Keyboard | x::Mapping::Modifier = event.request() {
+            self.update_keymap();
+        }
+    }
+
+    fn wait_for_event(&mut self) -> Option<xcb::Result<xcb::Event>> {
+        loop {
+            match self.connection.poll_for_event() {
+                Ok(Some(event)) => return Some(Ok(event)),
+                Ok(None) => {}
+                Err(err) => return Some(Err(err)),
+            }
+
+            let hover_timeout = self.hover.map(|(_, timeout)| timeout);
+
+            let timeout = match [self.mode_timeout, self.quit_timeout, hover_timeout]
+                .into_iter()
+                .flatten()
+                .min()
+            {
+                Some(timeout) => {
+                    let now = Instant::now();
+                    if now >= timeout {
+                        return None;
+                    }
+
+                    (timeout - now).as_millis() as i32 + 1
+                }
+                None => -1,
+            };
+
+            let _ = self.connection.flush();
+
+            let mut fds = [
+                libc::pollfd {
+                    fd: self.connection.as_raw_fd(),
+                    events: libc::POLLIN,
+                    revents: 0,
+                },
+                libc::pollfd {
+                    fd: self.signal_fd,
+                    events: libc::POLLIN,
+                    revents: 0,
+                },
+            ];
+
+            unsafe {
+                libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, timeout);
+            }
+
+            if fds[1].revents & libc::POLLIN != 0 {
+                self.reap_children();
+            }
+        }
+    }
+
+    fn spawn_child(&mut self, command: &str, args: &[&str]) -> Option<u32> {
+        match self.command(command, args).spawn() {
+            Ok(child) => {
+                let pid = child.id();
+                self.children.insert(pid, child);
+
+                Some(pid)
+            }
+            Err(err) => {
+                println!("{} {:?} couldn't be run: {}", command, args, err);
+
+                None
+            }
+        }
+    }
+
+    fn reap_children(&mut self) {
+        let mut info: libc::signalfd_siginfo = unsafe { mem::zeroed() };
+
+        while unsafe {
+            libc::read(
+                self.signal_fd,
+                &mut info as *mut _ as *mut libc::c_void,
+                mem::size_of::<libc::signalfd_siginfo>(),
+            )
+        } > 0
+        {}
+
+        loop {
+            let mut status = 0;
+            let pid = unsafe { libc::waitpid(-1, &mut status, libc::WNOHANG) };
+
+            if pid <= 0 {
+                break;
+            }
+
+            self.children.remove(&(pid as u32));
+
+            // A scratchpad that exits before mapping a window can be spawned again
+            self.pending_scratchpads
+                .retain(|_, (child, _)| *child != pid as u32);
+
+            println!("{} exited with {}", pid, ExitStatus::from_raw(status));
+        }
+    }
+
+    fn key_press(&mut self, event: x::KeyPressEvent) {
+        self.time = event.time();
+
+        let key_combos = self
+            .keymap
+            .key_combos(event.detail(), event.state(), KeyEvent::Press);
+        let keysym = self.keymap.get_keysym(event.detail(), event.state());
+
+        if let Some(mode) = self.mode {
+            if keys::is_modifier(keysym) {
+                return;
+            }
+
+            let command = key_combos.iter().find_map(|key_combo| mode.get(key_combo));
+
+            if !mode.persistent() {
+                self.exit_mode();
+            } else {
+                self.mode_timeout =
+                    Some(Instant::now() + Duration::from_millis(config::MODE_TIMEOUT));
+            }
+
+            if let Some(command) = command {
+                command(self);
+            }
+
+            return;
+        }
+
+        if let Some(action) = key_combos
+            .iter()
+            .find_map(|key_combo| self.keys.get(key_combo))
+            .cloned()
+        {
+            self.connection.send_request(&x::GrabKeyboard {
+                owner_events: false,
+                grab_window: self.root,
+                time: x::CURRENT_TIME,
+                pointer_mode: x::GrabMode::Async,
+                keyboard_mode: x::GrabMode::Async,
+            });
+            self.execute(action);
+
+            if self.mode.is_none() {
+                self.connection.send_request(&x::UngrabKeyboard {
+                    time: x::CURRENT_TIME,
+                });
+            }
+        }
+    }
+
+    fn key_release(&mut self, event: x::KeyReleaseEvent) {
+        self.time = event.time();
+
+        let keysym = self.keymap.get_keysym(event.detail(), event.state());
+
+        if self.mode.is_some() || keys::is_modifier(keysym) {
+            return;
+        }
+
+        if let Some(action) = self
+            .keymap
+            .key_combos(event.detail(), event.state(), KeyEvent::Release)
+            .iter()
+            .find_map(|key_combo| self.keys.get(key_combo))
+            .cloned()
+        {
+            self.execute(action);
+        }
+    }
+
+    fn raw_key_press(&mut self, event: xinput::RawKeyPressEvent) {
+        let keycode = event.detail() as x::Keycode;
+        let keysym = self.keymap.get_keysym(keycode, x::KeyButMask::empty());
+
+        self.tap = keys::is_modifier(keysym).then_some(keycode);
+    }
+
+    fn raw_key_release(&mut self, event: xinput::RawKeyReleaseEvent) {
+        let keycode = event.detail() as x::Keycode;
+
+        if self.tap.take() != Some(keycode) || self.mode.is_some() {
+            return;
+        }
+
+        // Raw events carry no modifier state
+        let Ok(reply) = self.connection.wait_for_reply(
+            self.connection
+                .send_request(&x::QueryPointer { window: self.root }),
+        ) else {
+            return;
+        };
+
+        self.time = event.time();
+
+        if let Some(action) = self
+            .keymap
+            .key_combos(keycode, reply.mask(), KeyEvent::Release)
+            .iter()
+            .find_map(|key_combo| self.keys.get(key_combo))
+            .cloned()
+        {
+            self.execute(action);
+        }
+    }
+
+    fn button_press(&mut self, event: x::ButtonPressEvent) {
+        self.time = event.time();
+
+        let click = if event.event() == self.root {
+            Click::RootWindow
+        } else if let Some((monitor, (click, tag))) =
+            self.monitors.iter().enumerate().find_map(|(i, monitor)| {
+                monitor
+                    .bar_click(event.event(), event.event_x())
+                    .map(|click| (i, click))
+            })
+        {
+            if self.monitor != monitor {
+                self.monitor = monitor;
+                self.focus(None);
+                self.draw_status();
+            }
+
+            self.clicked_tag = tag;
+            click
+        } else {
+            self.click(event.event(), event.root_x(), event.root_y());
+
+            self.connection.send_request(&x::AllowEvents {
+                mode: x::Allow::ReplayPointer,
+                time: event.time(),
+            });
+
+            Click::ClientWindow
+        };
+
+        let button_combo =
+            ButtonCombo::new(click, self.keymap.clean_mask(event.state()), event.detail());
+
+        if let Some(action) = self.buttons.get(&button_combo).cloned() {
+            self.execute(action);
+        }
+
+        self.clicked_tag = None;
+    }
+
+    fn click(&mut self, window: x::Window, x: i16, y: i16) {
+        self.focus_pointer(window, x, y);
+
+        if config::RAISE_ON_CLICK {
+            for monitor in &mut self.monitors {
+                if monitor.client(window).is_some_and(|client| client.floating) {
+                    monitor.raise(&self.connection, window);
+                }
+            }
+        }
+    }
+
+    fn button_release(&mut self, event: x::ButtonReleaseEvent) {
+        self.connection.send_request(&x::UngrabPointer {
+            time: x::CURRENT_TIME,
+        });
+
+        if let State::Dragging(window, _, _, _) = self.state {
+            self.drop(window, event.root_x(), event.root_y());
+        }
+
+        self.state = State::None
+    }
+
+    fn drop(&mut self, window: x::Window, x: i16, y: i16) {
+        if let Some(target) = self
+            .monitors
+            .iter()
+            .position(|monitor| monitor.contains(x, y))
+        {
+            if let Some(owner) = self
+                .monitors
+                .iter()
+                .position(|monitor| monitor.client(window).is_some())
+            {
+                if owner != target {
+                    self.send_to_monitor(window, owner, target);
+                }
+            }
+
+            if config::EDGE_TILING {
+                let monitor = &mut self.monitors[target];
+
+                if x <= monitor.x() {
+                    monitor.snap_half(&self.connection, window, Direction::Left);
+                } else if x >= monitor.x() + monitor.width() as i16 - 1 {
+                    monitor.snap_half(&self.connection, window, Direction::Right);
+                }
+            }
+        }
+    }
+
+    fn send_to_monitor(&mut self, window: x::Window, from: usize, to: usize) {
+        if let Some(client) = self.monitors[from].remove(&self.connection, window) {
+            // Floating clients already on the target keep their position, others carry
+            // theirs over relative to the monitor they came from
+            let monitor =
+                if self.monitors[to].overlaps(client.x, client.y, client.width, client.height) {
+                    to
+                } else {
+                    from
+                };
+
+            let x = self.monitors[monitor].x();
+            let y = self.monitors[monitor].y();
+            let width = self.monitors[monitor].width();
+            let height = self.monitors[monitor].height();
+            self.monitors[to].add(&self.connection, client, x, y, width, height);
+
+            self.monitor = to;
+            self.focus(Some(window));
+            self.draw_status();
+        }
+    }
+
+    fn map_request(&mut self, event: x::MapRequestEvent) {
+        if self
+            .monitors
+            .iter()
+            .any(|monitor| monitor.client(event.window()).is_some())
+        {
+            self.restore_window(event.window());
+
+            return;
+        }
+
+        if self
+            .scratchpads
+            .values()
+            .any(|client| client.window == event.window())
+        {
+            return;
+        }
+
+        self.connection.send_request(&x::ChangeWindowAttributes {
             window: event.window(),
-            property: self.atoms[WM_STATE],
-            r#type: self.atoms[WM_STATE],
-            data: &[1u32],
+            value_list: &[
+                x::Cw::BorderPixel(self.border_color),
+                x::Cw::EventMask(
+                    x::EventMask::ENTER_WINDOW
+                        | x::EventMask::LEAVE_WINDOW
+                        | x::EventMask::PROPERTY_CHANGE,
+                ),
+            ],
         });
 
-        self.connection.send_request(&x::MapWindow {
+        self.grab_buttons(event.window(), false);
+
+        let input = self
+            .get_property(event.window(), x::ATOM_WM_HINTS, x::ATOM_WM_HINTS)
+            .map_or(true, |property| WmHints::new(property.value()).input());
+
+        let geometry_cookie = self.connection.send_request(&x::GetGeometry {
+            drawable: x::Drawable::Window(event.window()),
+        });
+
+        let size_hints = self.get_size_hints(event.window());
+
+        let fullscreen = self
+            .get_atom_property(event.window(), self.atoms[_NET_WM_STATE])
+            .contains(&self.atoms[_NET_WM_STATE_FULLSCREEN]);
+        let transient_for = self
+            .get_property(event.window(), x::ATOM_WM_TRANSIENT_FOR, x::ATOM_WINDOW)
+            .ok()
+            .and_then(|property| property.value::<x::Window>().first().copied())
+            .filter(|parent| !parent.is_none() && *parent != event.window());
+
+        let floating = size_hints.fixed()
+            || transient_for.is_some()
+            || self
+                .get_atom_property(event.window(), self.atoms[_NET_WM_WINDOW_TYPE])
+                .contains(&self.atoms[_NET_WM_WINDOW_TYPE_DIALOG]);
+
+        let mut client = if let Ok(geometry) = self.connection.wait_for_reply(geometry_cookie) {
+            Client::new(
+                event.window(),
+                geometry.x(),
+                geometry.y(),
+                geometry.width(),
+                geometry.height(),
+                fullscreen,
+                floating,
+            )
+        } else {
+            Client::new(event.window(), 0, 0, 0, 0, fullscreen, floating)
+        };
+
+        if size_hints.fixed() {
+            client.width = size_hints.min_width();
+            client.height = size_hints.min_height();
+        }
+
+        client.input = input;
+        client.size_hints = size_hints;
+
+        let class = self.get_class(event.window());
+        let title = self.get_name(event.window());
+
+        if let Some(scratchpad) = config::SCRATCHPADS.iter().find(|scratchpad| {
+            scratchpad.matches(&class, &title) && !self.has_scratchpad(scratchpad.name())
+        }) {
+            client.scratchpad = Some(scratchpad.name());
+            client.floating = true;
+
+            self.pending_scratchpads.remove(scratchpad.name());
+
+            self.monitors[self.monitor].center(&mut client);
+        }
+
+        // The parent only places the transient when it is managed
+        let parent = transient_for
+            .filter(|_| client.scratchpad.is_none())
+            .and_then(|parent| {
+                self.monitors
+                    .iter()
+                    .enumerate()
+                    .find_map(|(i, monitor)| monitor.locate(parent).map(|parent| (i, parent)))
+            })
+            .map(|(monitor, (tag, parent))| {
+                (
+                    monitor,
+                    tag,
+                    parent.window,
+                    parent.x + (parent.width as i16 - client.width as i16) / 2,
+                    parent.y + (parent.height as i16 - client.height as i16) / 2,
+                )
+            });
+
+        client.pid = self.get_pid(event.window());
+        client.terminal = class
+            .iter()
+            .any(|class| config::TERMINALS.contains(&class.as_str()));
+
+        let terminal = client
+            .pid
+            .filter(|_| {
+                transient_for.is_none()
+                    && client.scratchpad.is_none()
+                    && !client.terminal
+                    && !class
+                        .iter()
+                        .any(|class| config::NO_SWALLOW.contains(&class.as_str()))
+            })
+            .and_then(|pid| {
+                self.monitors.iter().enumerate().find_map(|(i, monitor)| {
+                    monitor
+                        .terminals()
+                        .into_iter()
+                        .find(|(_, terminal)| is_descendant(pid, *terminal))
+                        .map(|(window, _)| (i, window))
+                })
+            });
+
+        if let Some((monitor, tag, parent, x, y)) = parent {
+            client.transient_for = Some(parent);
+            client.x = x;
+            client.y = y;
+
+            self.monitors[monitor].map(&self.connection, client, tag);
+        } else if let Some((monitor, terminal)) = terminal {
+            if Some(terminal) == self.focused {
+                self.focus(None);
+            }
+
+            self.monitors[monitor].swallow(&self.connection, terminal, client);
+        } else {
+            let tag = self.monitors[self.monitor].selected_tag();
+            self.monitors[self.monitor].map(&self.connection, client, tag);
+        }
+
+        self.connection.send_request(&x::ChangeProperty {
+            mode: x::PropMode::Replace,
             window: event.window(),
+            property: self.atoms[WM_STATE],
+            r#type: self.atoms[WM_STATE],
+            data: &[NORMAL_STATE, 0],
         });
+
+        // Offscreen hidden clients still need to be mapped
+        if !config::HIDE_BY_UNMAP
+            || !self
+                .monitors
+                .iter()
+                .filter_map(|monitor| monitor.client(event.window()))
+                .any(|client| client.hidden)
+        {
+            self.connection.send_request(&x::MapWindow {
+                window: event.window(),
+            });
+        }
+
+        self.update_urgency(event.window());
+    }
+
+    fn unmap_notify(&mut self, event: x::UnmapNotifyEvent) {
+        let synthetic = event.response_type() & 0x80 != 0;
+
+        if !synthetic
+            && (self
+                .monitors
+                .iter_mut()
+                .any(|monitor| monitor.ignore_unmap(event.window()))
+                || self
+                    .scratchpads
+                    .values_mut()
+                    .any(|client| client.window == event.window() && client.ignore_unmap()))
+        {
+            return;
+        }
+
+        self.unmap(event.window());
     }
 
     fn unmap(&mut self, window: x::Window) {
@@ -549,21 +1504,10 @@ impl Rwm {
             monitor.unmap(&self.connection, window);
         }
 
-        self.connection.send_request(&x::DeleteProperty {
-            window: self.root,
-            property: self.atoms[_NET_CLIENT_LIST],
-        });
+        self.scratchpads.retain(|_, client| client.window != window);
 
-        for monitor in &self.monitors {
-            for client in monitor.clients() {
-                self.connection.send_request(&x::ChangeProperty {
-                    mode: x::PropMode::Append,
-                    window: self.root,
-                    property: self.atoms[_NET_CLIENT_LIST],
-                    r#type: x::ATOM_WINDOW,
-                    data: &[client],
-                });
-            }
+        if self.hover.is_some_and(|(hover, _)| hover == window) {
+            self.hover = None;
         }
     }
 
@@ -586,8 +1530,44 @@ impl Rwm {
     }
 
     fn enter_notify(&mut self, event: x::EnterNotifyEvent) {
+        self.time = event.time();
+
+        match self.focus_mode {
+            FocusMode::Sloppy => self.focus_pointer(event.event(), event.root_x(), event.root_y()),
+            FocusMode::Delayed(delay) => {
+                self.hover = Some((
+                    event.event(),
+                    Instant::now() + Duration::from_millis(delay as u64),
+                ))
+            }
+            FocusMode::Click => {}
+        }
+    }
+
+    fn leave_notify(&mut self, event: x::LeaveNotifyEvent) {
+        if event.detail() != x::NotifyDetail::Inferior
+            && self
+                .hover
+                .is_some_and(|(window, _)| window == event.event())
+        {
+            self.hover = None;
+        }
+    }
+
+    fn focus_hovered(&mut self, window: x::Window) {
+        if let Ok(pointer_reply) = self.connection.wait_for_reply(
+            self.connection
+                .send_request(&x::QueryPointer { window: self.root }),
+        ) {
+            if pointer_reply.child() == window {
+                self.focus_pointer(window, pointer_reply.root_x(), pointer_reply.root_y());
+            }
+        }
+    }
+
+    fn focus_pointer(&mut self, window: x::Window, x: i16, y: i16) {
         for (i, monitor) in self.monitors.iter().enumerate() {
-            if monitor.contains(event.root_x(), event.root_y()) {
+            if monitor.contains(x, y) {
                 if self.monitor != i {
                     self.monitor = i;
                     self.focus(None);
@@ -597,38 +1577,71 @@ impl Rwm {
             }
         }
 
-        self.focus(Some(event.event()));
+        self.focus(Some(window));
         self.draw_status();
     }
 
     fn motion_notify(&mut self, event: x::MotionNotifyEvent) {
+        self.time = event.time();
+
         match self.state {
-            State::Dragging(window, x, y, time) => {
+            State::Dragging(window, x_offset, y_offset, time) => {
                 if time == 0 || event.time() - time > (1000 / 60) {
-                    let x_delta = event.root_x() - x;
-                    let y_delta = event.root_y() - y;
+                    let (x, y) = self.snap(
+                        window,
+                        event.root_x(),
+                        event.root_y(),
+                        event.root_x() - x_offset,
+                        event.root_y() - y_offset,
+                    );
 
-                    for monitor in &mut self.monitors {
-                        monitor.drag(&self.connection, window, x_delta, y_delta);
+                    if let Some(client) = self
+                        .monitors
+                        .iter()
+                        .find_map(|monitor| monitor.client(window))
+                    {
+                        let x_delta = x - client.x;
+                        let y_delta = y - client.y;
+
+                        for monitor in &mut self.monitors {
+                            monitor.drag(&self.connection, window, x_delta, y_delta);
+                        }
                     }
 
-                    self.state =
-                        State::Dragging(window, event.root_x(), event.root_y(), event.time());
+                    self.state = State::Dragging(window, x_offset, y_offset, event.time());
                 }
             }
-            State::Resizing(window, x, y, time)
+            State::Resizing(window, edges, time)
                 if time == 0 || event.time() - time > (1000 / 60) =>
             {
-                let x_delta = event.root_x() - x;
-                let y_delta = event.root_y() - y;
-
                 for monitor in &mut self.monitors {
-                    monitor.resize(&self.connection, window, x_delta, y_delta);
+                    monitor.resize_edges(
+                        &self.connection,
+                        window,
+                        event.root_x(),
+                        event.root_y(),
+                        edges,
+                    );
                 }
 
-                self.state = State::Resizing(window, event.root_x(), event.root_y(), event.time());
+                self.state = State::Resizing(window, edges, event.time());
+            }
+            State::ResizingMain(monitor, time)
+                if time == 0 || event.time() - time > (1000 / 60) =>
+            {
+                self.monitors[monitor].resize_main(&self.connection, event.root_x());
+
+                self.state = State::ResizingMain(monitor, event.time());
             }
             _ => {
+                if event.event() != self.root {
+                    return;
+                }
+
+                if let FocusMode::Click = self.focus_mode {
+                    return;
+                }
+
                 for (i, monitor) in self.monitors.iter().enumerate() {
                     if monitor.contains(event.root_x(), event.root_y()) {
                         if self.monitor != i {
@@ -643,7 +1656,38 @@ impl Rwm {
         }
     }
 
+    fn snap(
+        &self,
+        window: x::Window,
+        pointer_x: i16,
+        pointer_y: i16,
+        x: i16,
+        y: i16,
+    ) -> (i16, i16) {
+        let client = self
+            .monitors
+            .iter()
+            .find_map(|monitor| monitor.client(window));
+        let monitor = self
+            .monitors
+            .iter()
+            .find(|monitor| monitor.contains(pointer_x, pointer_y));
+
+        if let (Some(client), Some(monitor)) = (client, monitor) {
+            let (x_edges, y_edges) = monitor.edges(window);
+
+            (
+                snap(x, client.width + 2 * config::BORDER_WIDTH, &x_edges),
+                snap(y, client.height + 2 * config::BORDER_WIDTH, &y_edges),
+            )
+        } else {
+            (x, y)
+        }
+    }
+
     fn property_notify(&mut self, event: x::PropertyNotifyEvent) {
+        self.time = event.time();
+
         if event.atom() == self.atoms[_NET_WM_WINDOW_TYPE]
             && self
                 .get_atom_property(event.window(), self.atoms[_NET_WM_WINDOW_TYPE])
@@ -652,12 +1696,53 @@ impl Rwm {
             for monitor in &mut self.monitors {
                 monitor.set_floating(&self.connection, event.window());
             }
+        } else if event.atom() == x::ATOM_WM_NORMAL_HINTS {
+            let size_hints = self.get_size_hints(event.window());
+
+            for monitor in &mut self.monitors {
+                monitor.set_size_hints(&self.connection, event.window(), size_hints);
+            }
+        } else if event.atom() == x::ATOM_WM_HINTS || event.atom() == self.atoms[_NET_WM_STATE] {
+            self.update_urgency(event.window());
+        } else if event.window() == self.root && event.atom() == self.atoms[_RWM_COMMAND] {
+            if event.state() == x::Property::NewValue {
+                let message = self
+                    .get_property(self.root, self.atoms[_RWM_COMMAND], x::ATOM_ANY)
+                    .ok()
+                    .and_then(|reply| {
+                        std::str::from_utf8(reply.value())
+                            .ok()
+                            .map(|s| s.to_string())
+                    })
+                    .unwrap_or_default();
+
+                self.connection.send_request(&x::DeleteProperty {
+                    window: self.root,
+                    property: self.atoms[_RWM_COMMAND],
+                });
+
+                self.ipc(&message);
+            }
         } else {
             self.draw_status();
         }
     }
 
     fn client_message(&mut self, event: x::ClientMessageEvent) {
+        if event.r#type() == self.atoms[WM_CHANGE_STATE] {
+            if let x::ClientMessageData::Data32(data) = event.data() {
+                if data[0] == ICONIC_STATE {
+                    self.minimize_window(event.window());
+                } else if data[0] == NORMAL_STATE {
+                    self.restore_window(event.window());
+                }
+            }
+        }
+
+        if event.r#type() == self.atoms[_NET_ACTIVE_WINDOW] {
+            self.restore_window(event.window());
+        }
+
         if event.r#type() == self.atoms[_NET_WM_STATE] {
             if let x::ClientMessageData::Data32(data) = event.data() {
                 if data[1] == self.atoms[_NET_WM_STATE_FULLSCREEN].resource_id() {
@@ -669,6 +1754,35 @@ impl Rwm {
                         }
                     }
                 }
+
+                let demands_attention = self.atoms[_NET_WM_STATE_DEMANDS_ATTENTION];
+                if data[1] == demands_attention.resource_id()
+                    || data[2] == demands_attention.resource_id()
+                {
+                    let mut state =
+                        self.get_atom_property(event.window(), self.atoms[_NET_WM_STATE]);
+                    let urgent = match data[0] {
+                        0 => false,
+                        1 => true,
+                        _ => !state.contains(&demands_attention),
+                    };
+
+                    state.retain(|atom| *atom != demands_attention);
+
+                    if urgent {
+                        state.push(demands_attention);
+                    }
+
+                    self.connection.send_request(&x::ChangeProperty {
+                        mode: x::PropMode::Replace,
+                        window: event.window(),
+                        property: self.atoms[_NET_WM_STATE],
+                        r#type: x::ATOM_ATOM,
+                        data: &state,
+                    });
+
+                    self.update_urgency(event.window());
+                }
             }
         }
     }
@@ -683,6 +1797,8 @@ impl Rwm {
                 window,
                 value_list: &[x::Cw::BorderPixel(self.border_color)],
             });
+
+            self.grab_buttons(window, false);
         }
 
         if let Some(window) = focused {
@@ -690,11 +1806,25 @@ impl Rwm {
                 window,
                 value_list: &[x::Cw::BorderPixel(self.border_hl_color)],
             });
-            self.connection.send_request(&x::SetInputFocus {
-                revert_to: x::InputFocus::PointerRoot,
-                focus: window,
-                time: x::CURRENT_TIME,
-            });
+
+            self.grab_buttons(window, true);
+
+            // ICCCM input models: passive and locally active clients get the input focus,
+            // locally and globally active clients are asked to take it themselves and
+            // clients without input don't get it at all.
+            let input = self
+                .monitors
+                .iter()
+                .find_map(|monitor| monitor.client(window))
+                .is_none_or(|client| client.input);
+
+            if input {
+                self.connection.send_request(&x::SetInputFocus {
+                    revert_to: x::InputFocus::PointerRoot,
+                    focus: window,
+                    time: self.time,
+                });
+            }
 
             self.connection.send_request(&x::ChangeProperty {
                 mode: x::PropMode::Replace,
@@ -704,7 +1834,23 @@ impl Rwm {
                 data: &[window],
             });
 
-            self.send_event(window, self.atoms[WM_TAKE_FOCUS]);
+            if !self.send_event(window, self.atoms[WM_TAKE_FOCUS]) && !input {
+                self.connection.send_request(&x::SetInputFocus {
+                    revert_to: x::InputFocus::PointerRoot,
+                    focus: self.root,
+                    time: self.time,
+                });
+            }
+
+            if self.set_urgent(window, false) {
+                self.clear_urgency(window);
+            }
+
+            if config::RAISE_ON_FOCUS {
+                for monitor in &mut self.monitors {
+                    monitor.raise(&self.connection, window);
+                }
+            }
         } else {
             self.connection.send_request(&x::DeleteProperty {
                 window: self.root,
@@ -715,6 +1861,234 @@ impl Rwm {
         self.focused = focused;
     }
 
+    fn grab_buttons(&self, window: x::Window, focused: bool) {
+        self.connection.send_request(&x::UngrabButton {
+            button: x::ButtonIndex::Any,
+            grab_window: window,
+            modifiers: x::ModMask::ANY,
+        });
+
+        if let (FocusMode::Click, false) = (self.focus_mode, focused) {
+            self.connection.send_request(&x::GrabButton {
+                owner_events: false,
+                grab_window: window,
+                event_mask: x::EventMask::BUTTON_PRESS,
+                pointer_mode: x::GrabMode::Sync,
+                keyboard_mode: x::GrabMode::Async,
+                confine_to: x::WINDOW_NONE,
+                cursor: x::CURSOR_NONE,
+                button: x::ButtonIndex::N1,
+                modifiers: x::ModMask::ANY,
+            });
+        }
+
+        for button_combo in self
+            .buttons
+            .keys()
+            .filter(|button_combo| button_combo.click() == Click::ClientWindow)
+        {
+            for modifiers in self.keymap.lock_masks(button_combo.mask()) {
+                self.connection.send_request(&x::GrabButton {
+                    owner_events: false,
+                    grab_window: window,
+                    event_mask: x::EventMask::BUTTON_PRESS | x::EventMask::BUTTON_RELEASE,
+                    pointer_mode: x::GrabMode::Async,
+                    keyboard_mode: x::GrabMode::Async,
+                    confine_to: x::WINDOW_NONE,
+                    cursor: x::CURSOR_NONE,
+                    button: button_combo.button(),
+                    modifiers,
+                });
+            }
+        }
+    }
+
+    fn restore_window(&mut self, window: x::Window) {
+        if let Some((monitor, tag)) = self.monitors.iter().enumerate().find_map(|(i, monitor)| {
+            monitor
+                .locate(window)
+                .filter(|_| monitor.is_minimized(window))
+                .map(|(tag, _)| (i, tag))
+        }) {
+            if self.monitor != monitor {
+                self.monitor = monitor;
+                self.focus(None);
+            }
+
+            self.view(tag);
+            self.monitors[monitor].restore(&self.connection, window);
+            self.focus(Some(window));
+            self.draw_status();
+        }
+    }
+
+    fn minimize_window(&mut self, window: x::Window) {
+        if self
+            .monitors
+            .iter_mut()
+            .any(|monitor| monitor.minimize(&self.connection, window))
+        {
+            if Some(window) == self.focused {
+                self.focus(None);
+            }
+
+            self.draw_status();
+        }
+    }
+
+    fn update_client_list(&mut self) {
+        let scratchpads = self.scratchpads.values().map(|client| client.window);
+
+        let client_list: Vec<x::Window> = self
+            .monitors
+            .iter()
+            .flat_map(|monitor| monitor.clients())
+            .chain(scratchpads.clone())
+            .collect();
+        let client_list_stacking: Vec<x::Window> = scratchpads
+            .chain(self.monitors.iter().flat_map(|monitor| monitor.stacking()))
+            .collect();
+
+        if client_list != self.client_list {
+            self.connection.send_request(&x::ChangeProperty {
+                mode: x::PropMode::Replace,
+                window: self.root,
+                property: self.atoms[_NET_CLIENT_LIST],
+                r#type: x::ATOM_WINDOW,
+                data: &client_list,
+            });
+
+            self.client_list = client_list;
+        }
+
+        if client_list_stacking != self.client_list_stacking {
+            self.connection.send_request(&x::ChangeProperty {
+                mode: x::PropMode::Replace,
+                window: self.root,
+                property: self.atoms[_NET_CLIENT_LIST_STACKING],
+                r#type: x::ATOM_WINDOW,
+                data: &client_list_stacking,
+            });
+
+            self.client_list_stacking = client_list_stacking;
+        }
+    }
+
+    fn update_client_states(&mut self) {
+        let hidden: HashSet<x::Window> = self
+            .monitors
+            .iter()
+            .flat_map(|monitor| monitor.hidden())
+            .chain(self.scratchpads.values().map(|client| client.window))
+            .collect();
+
+        for window in hidden.difference(&self.hidden) {
+            self.set_iconic(*window, true);
+        }
+
+        for window in self.hidden.difference(&hidden) {
+            if self
+                .monitors
+                .iter()
+                .any(|monitor| monitor.client(*window).is_some())
+                || self
+                    .scratchpads
+                    .values()
+                    .any(|client| client.window == *window)
+            {
+                self.set_iconic(*window, false);
+            }
+        }
+
+        self.hidden = hidden;
+    }
+
+    fn set_iconic(&self, window: x::Window, iconic: bool) {
+        self.connection.send_request(&x::ChangeProperty {
+            mode: x::PropMode::Replace,
+            window,
+            property: self.atoms[WM_STATE],
+            r#type: self.atoms[WM_STATE],
+            data: &[if iconic { ICONIC_STATE } else { NORMAL_STATE }, 0],
+        });
+
+        let mut state = self.get_atom_property(window, self.atoms[_NET_WM_STATE]);
+        state.retain(|atom| *atom != self.atoms[_NET_WM_STATE_HIDDEN]);
+
+        if iconic {
+            state.push(self.atoms[_NET_WM_STATE_HIDDEN]);
+        }
+
+        self.connection.send_request(&x::ChangeProperty {
+            mode: x::PropMode::Replace,
+            window,
+            property: self.atoms[_NET_WM_STATE],
+            r#type: x::ATOM_ATOM,
+            data: &state,
+        });
+    }
+
+    fn set_urgent(&mut self, window: x::Window, urgent: bool) -> bool {
+        let mut changed = false;
+
+        for monitor in &mut self.monitors {
+            changed |= monitor.set_urgent(window, urgent);
+        }
+
+        changed
+    }
+
+    fn update_urgency(&mut self, window: x::Window) {
+        let urgent = self
+            .get_property(window, x::ATOM_WM_HINTS, x::ATOM_WM_HINTS)
+            .is_ok_and(|property| WmHints::new(property.value()).urgent())
+            || self
+                .get_atom_property(window, self.atoms[_NET_WM_STATE])
+                .contains(&self.atoms[_NET_WM_STATE_DEMANDS_ATTENTION]);
+
+        if Some(window) == self.focused {
+            if urgent {
+                self.clear_urgency(window);
+            }
+        } else {
+            self.set_urgent(window, urgent);
+        }
+    }
+
+    fn clear_urgency(&self, window: x::Window) {
+        if let Ok(property) = self.get_property(window, x::ATOM_WM_HINTS, x::ATOM_WM_HINTS) {
+            let mut hints = WmHints::new(property.value());
+
+            if hints.urgent() {
+                hints.clear_urgent();
+
+                self.connection.send_request(&x::ChangeProperty {
+                    mode: x::PropMode::Replace,
+                    window,
+                    property: x::ATOM_WM_HINTS,
+                    r#type: x::ATOM_WM_HINTS,
+                    data: hints.data(),
+                });
+            }
+        }
+
+        let state = self.get_atom_property(window, self.atoms[_NET_WM_STATE]);
+        if state.contains(&self.atoms[_NET_WM_STATE_DEMANDS_ATTENTION]) {
+            let state: Vec<x::Atom> = state
+                .into_iter()
+                .filter(|atom| *atom != self.atoms[_NET_WM_STATE_DEMANDS_ATTENTION])
+                .collect();
+
+            self.connection.send_request(&x::ChangeProperty {
+                mode: x::PropMode::Replace,
+                window,
+                property: self.atoms[_NET_WM_STATE],
+                r#type: x::ATOM_ATOM,
+                data: &state,
+            });
+        }
+    }
+
     fn update_monitors(&mut self) {
         let mut dirty = false;
 
@@ -794,6 +2168,26 @@ impl Rwm {
         }
     }
 
+    fn command(&self, command: &str, args: &[&str]) -> Command {
+        let mut process = Command::new(command);
+        process.args(args).env("DISPLAY", &self.display);
+
+        // Start the child in its own session, so it survives rwm and its terminal, and
+        // give it back the SIGCHLD rwm blocks for its signalfd.
+        unsafe {
+            process.pre_exec(|| {
+                let mut mask: libc::sigset_t = mem::zeroed();
+                libc::sigemptyset(&mut mask);
+                libc::sigprocmask(libc::SIG_SETMASK, &mask, std::ptr::null_mut());
+
+                libc::setsid();
+                Ok(())
+            });
+        }
+
+        process
+    }
+
     fn get_property(
         &self,
         window: x::Window,
@@ -811,6 +2205,14 @@ impl Rwm {
             }))
     }
 
+    fn get_size_hints(&self, window: x::Window) -> SizeHints {
+        self.get_property(window, x::ATOM_WM_NORMAL_HINTS, x::ATOM_WM_SIZE_HINTS)
+            .map_or_else(
+                |_| SizeHints::default(),
+                |property| SizeHints::new(property.value()),
+            )
+    }
+
     fn get_atom_property(&self, window: x::Window, property: x::Atom) -> Vec<x::Atom> {
         if let Ok(property_reply) = self.get_property(window, property, x::ATOM_ATOM) {
             property_reply.value().to_vec()
@@ -827,7 +2229,7 @@ impl Rwm {
             let event = x::ClientMessageEvent::new(
                 window,
                 self.atoms[WM_PROTOCOLS],
-                x::ClientMessageData::Data32([atom.resource_id(), x::CURRENT_TIME, 0, 0, 0]),
+                x::ClientMessageData::Data32([atom.resource_id(), self.time, 0, 0, 0]),
             );
             self.connection.send_request(&x::SendEvent {
                 propagate: false,
@@ -842,19 +2244,49 @@ impl Rwm {
         }
     }
 
+    fn has_scratchpad(&self, name: &str) -> bool {
+        self.scratchpads.contains_key(name)
+            || self
+                .monitors
+                .iter()
+                .any(|monitor| monitor.scratchpad(name).is_some())
+    }
+
+    fn get_pid(&self, window: x::Window) -> Option<u32> {
+        self.get_property(window, self.atoms[_NET_WM_PID], x::ATOM_CARDINAL)
+            .ok()
+            .and_then(|reply| reply.value::<u32>().first().copied())
+    }
+
+    fn get_class(&self, window: x::Window) -> Vec<String> {
+        self.get_property(window, x::ATOM_WM_CLASS, x::ATOM_STRING)
+            .map(|reply| {
+                reply
+                    .value::<u8>()
+                    .split(|byte| *byte == 0)
+                    .filter(|class| !class.is_empty())
+                    .map(|class| String::from_utf8_lossy(class).to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_name(&self, window: x::Window) -> String {
+        self.get_property(window, x::ATOM_WM_NAME, self.atoms[UTF8_STRING])
+            .ok()
+            .and_then(|reply| {
+                std::str::from_utf8(reply.value())
+                    .ok()
+                    .map(|s| s.to_string())
+            })
+            .unwrap_or_default()
+    }
+
     fn draw_status(&self) {
         let name = self
             .focused
-            .and_then(|window| {
-                self.get_property(window, x::ATOM_WM_NAME, self.atoms[UTF8_STRING])
-                    .ok()
-                    .and_then(|reply| {
-                        std::str::from_utf8(reply.value())
-                            .ok()
-                            .map(|s| s.to_string())
-                    })
-            })
-            .unwrap_or_else(|| "".to_string());
+            .map(|window| self.get_name(window))
+            .unwrap_or_default();
 
         let status = self
             .get_property(self.root, x::ATOM_WM_NAME, x::ATOM_STRING)
@@ -867,11 +2299,100 @@ impl Rwm {
             .unwrap_or_else(|| "".to_string());
 
         for (i, monitor) in self.monitors.iter().enumerate() {
+            let minimized: Vec<String> = monitor
+                .minimized()
+                .into_iter()
+                .map(|window| self.get_name(window))
+                .collect();
+
             if i == self.monitor {
-                monitor.draw_status(&name, &status);
+                monitor.draw_status(
+                    self.mode.map(|mode| mode.name()),
+                    &name,
+                    &minimized,
+                    &status,
+                );
             } else {
-                monitor.draw_status("", &status);
+                monitor.draw_status(None, "", &minimized, &status);
             }
         }
     }
 }
+
+fn snap(position: i16, size: u16, edges: &[i16]) -> i16 {
+    edges
+        .iter()
+        .flat_map(|edge| [*edge, *edge - size as i16])
+        .filter(|snapped| (position - snapped).abs() < config::SNAP as i16)
+        .min_by_key(|snapped| (position - snapped).abs())
+        .unwrap_or(position)
+}
+
+fn config_directory() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|directory| directory.join("rwm"))
+}
+
+fn signal_fd() -> RawFd {
+    unsafe {
+        let mut mask: libc::sigset_t = mem::zeroed();
+        libc::sigemptyset(&mut mask);
+        libc::sigaddset(&mut mask, libc::SIGCHLD);
+        libc::sigprocmask(libc::SIG_BLOCK, &mask, std::ptr::null_mut());
+
+        libc::signalfd(-1, &mask, libc::SFD_NONBLOCK | libc::SFD_CLOEXEC)
+    }
+}
+
+fn get_keymap(connection: &xcb::Connection) -> xcb::Result<Keymap> {
+    let setup = connection.get_setup();
+
+    let keyboard_mapping_cookie = connection.send_request(&x::GetKeyboardMapping {
+        first_keycode: setup.min_keycode(),
+        count: setup.max_keycode() - setup.min_keycode() + 1,
+    });
+    let modifier_mapping_cookie = connection.send_request(&x::GetModifierMapping {});
+
+    let keyboard_mapping = connection.wait_for_reply(keyboard_mapping_cookie)?;
+    let modifier_mapping = connection.wait_for_reply(modifier_mapping_cookie)?;
+
+    Ok(Keymap::new(
+        keyboard_mapping.keysyms().to_vec(),
+        setup.min_keycode(),
+        keyboard_mapping.keysyms_per_keycode(),
+        modifier_mapping.keycodes(),
+        modifier_mapping.keycodes_per_modifier(),
+    ))
+}
+
+fn is_descendant(pid: u32, ancestor: u32) -> bool {
+    let mut pid = pid;
+
+    while let Some(parent) = parent_pid(pid) {
+        if parent == ancestor {
+            return true;
+        }
+
+        if parent <= 1 {
+            break;
+        }
+
+        pid = parent;
+    }
+
+    false
+}
+
+fn parent_pid(pid: u32) -> Option<u32> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+
+    stat.rsplit_once(')')?
+        .1
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+}
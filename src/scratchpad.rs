@@ -0,0 +1,39 @@
+pub enum Matcher {
+    Class(&'static str),
+    Title(&'static str),
+}
+
+pub struct Scratchpad {
+    name: &'static str,
+    command: &'static [&'static str],
+    matcher: Matcher,
+}
+
+impl Scratchpad {
+    pub const fn new(
+        name: &'static str,
+        command: &'static [&'static str],
+        matcher: Matcher,
+    ) -> Self {
+        Self {
+            name,
+            command,
+            matcher,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn command(&self) -> &'static [&'static str] {
+        self.command
+    }
+
+    pub fn matches(&self, class: &[String], title: &str) -> bool {
+        match self.matcher {
+            Matcher::Class(name) => class.iter().any(|class| class == name),
+            Matcher::Title(name) => title == name,
+        }
+    }
+}